@@ -0,0 +1,157 @@
+# Compounder, Deutsch
+
+## Calculator
+start-date = STARTDATUM
+final-date = ENDDATUM
+years = Jahre
+months = Monate
+weeks = Wochen
+days = Tage
+start-amount = STARTBETRAG
+final-amount = ENDBETRAG
+cagr = CAGR
+cagr-bp = CAGR (BP)
+number-drag = Zum Anpassen ziehen
+number-down = Verringern
+number-up = Erhöhen
+
+## Validation
+error-date = Erwartet wird ein Datum wie { $example }
+error-start-after-final = Startdatum liegt nach dem Enddatum
+error-final-before-start = Enddatum liegt vor dem Startdatum
+error-amount = Erwartet wird ein Betrag oder eine Berechnung
+error-rate = Erwartet wird eine Rendite oder eine Berechnung
+error-rate-range = Erwartet wird eine Rendite zwischen { $low } und { $high }
+error-syntax = Unerwartete Eingabe an Position { $position }
+error-unknown-name = Unbekannter Name { $name }, verwende A, B oder C
+error-division-by-zero = Division durch null
+error-too-large = Das Ergebnis ist zu groß
+error-currency = Verwende ein Symbol wie $ oder einen Code aus höchstens drei Buchstaben
+
+## History
+history = VERLAUF
+undo = rückgängig
+redo = wiederholen
+list = Liste
+keys = Tasten
+history-title = Verlauf
+history-current = aktuell
+edit-start-date = Startdatum
+edit-final-date = Enddatum
+edit-years = Jahre
+edit-months = Monate
+edit-weeks = Wochen
+edit-days = Tage
+edit-start-amount = Startbetrag
+edit-final-amount = Endbetrag
+edit-cagr = CAGR
+edit-recalculate = Neuberechnung
+edit-number-format = Zahlenformat
+edit-currency = Währung
+edit-rate-format = Renditeformat
+edit-language = Sprache
+edit-import = Import
+
+## Settings
+dark-mode = DUNKELMODUS
+dark-mode-switch = Dunkelmodus
+mode-system = wie System
+scale = SKALIERUNG
+scale-zoom = Zoom
+scale-text = Text
+language = SPRACHE
+number-format = ZAHLENFORMAT
+currency = WÄHRUNG
+rate-precision = GENAUIGKEIT
+decimals = Dezimalstellen
+significant = signifikant
+rate-unit = EINHEIT
+
+## Sensitivity
+table = Tabelle
+sensitivity-title = Sensitivität
+sensitivity-from = von
+sensitivity-to = bis
+sensitivity-step = Schritt
+sensitivity-rates = CAGR
+sensitivity-years = Jahre
+
+## Simulation
+simulate = Simulation
+simulation-title = Simulation
+simulation-expected = Erwartete Rendite
+simulation-from-cagr = aus CAGR
+simulation-volatility = Volatilität
+simulation-fat-tails = Breite Ränder
+simulation-runs = Durchläufe
+simulation-seed = Startwert
+simulation-target = Ziel
+simulation-percentile = { $p } % enden unter
+simulation-probability = Chance, { $target } zu erreichen: { $chance } %
+
+## Import
+import = Import
+series-title = Kursreihe
+series-file = CSV-Datei
+series-load = laden
+series-from = Von
+series-to = Bis
+series-use = diese Daten und Kurse übernehmen
+series-risk-free = Risikofreier Zins
+series-window = Rollierende Jahre
+series-cagr = CAGR
+series-volatility = Volatilität
+series-drawdown = Maximaler Rückgang
+series-sharpe = Sharpe-Ratio
+series-sortino = Sortino-Ratio
+series-best = Beste rollierende Periode
+series-worst = Schlechteste rollierende Periode
+series-windows = Fenster
+series-median = Median
+series-rolling-none = Kein Fenster passt in die Auswahl, oder nur über Lücken in den Kursen
+series-skipped = { $rows } Zeilen ohne Datum oder Kurs übersprungen
+error-series-dates = Keine Datumsspalte in einem bekannten Format
+error-series-few = Mindestens zwei datierte Kurse nötig
+
+## Theme
+theme = THEMA
+theme-edit = bearbeiten
+theme-title = Thema
+theme-accent = Akzentfarbe
+theme-dark = Dunkler Hintergrund und Text
+theme-light = Heller Hintergrund und Text
+theme-gradient = Verlauf zu dunkel und hell
+theme-shape = Form
+shape-linear = linear
+shape-radial = radial
+theme-interpolation = Mischung
+mix-gamma = sRGB
+mix-linear = lineares Licht
+mix-oklab = Oklab
+theme-cache = Verlaufscache: { $textures } Texturen, { $kb } kB, { $hits } Treffer, { $misses } Fehlschläge
+theme-rounding = Eckenradius
+theme-spacing = Abstand
+theme-file = Themendatei
+theme-import = importieren
+theme-export = exportieren
+theme-reset = zurücksetzen
+font-file = Schriftdatei
+font-load = laden
+font-default = Standard
+
+## Shortcuts
+shortcuts-title = Tastenkürzel
+key-enter = Eingabe
+key-tab = Tab
+key-space = Leertaste
+shortcut-recalculate = neu berechnen
+shortcut-undo = rückgängig
+shortcut-redo = wiederholen
+shortcut-dark-mode = Dunkelmodus
+shortcut-larger = vergrößern
+shortcut-smaller = verkleinern
+shortcut-reset-zoom = Zoom zurücksetzen
+shortcut-save = speichern
+shortcut-help = diese Liste
+shortcut-next-field = nächstes Feld
+shortcut-toggle = Schalter umschalten
@@ -0,0 +1,157 @@
+# Compounder, English
+
+## Calculator
+start-date = START DATE
+final-date = FINAL DATE
+years = years
+months = months
+weeks = weeks
+days = days
+start-amount = START AMOUNT
+final-amount = FINAL AMOUNT
+cagr = CAGR
+cagr-bp = CAGR (BP)
+number-drag = Drag to adjust
+number-down = Decrease
+number-up = Increase
+
+## Validation
+error-date = Expected a date like { $example }
+error-start-after-final = Start date is after final date
+error-final-before-start = Final date is before start date
+error-amount = Expected an amount or a calculation
+error-rate = Expected a rate or a calculation
+error-rate-range = Expected a rate between { $low } and { $high }
+error-syntax = Unexpected input at position { $position }
+error-unknown-name = Unknown name { $name }, use A, B or C
+error-division-by-zero = Division by zero
+error-too-large = The result is too large
+error-currency = Use a symbol like $ or a code of up to three letters
+
+## History
+history = HISTORY
+undo = undo
+redo = redo
+list = list
+keys = keys
+history-title = History
+history-current = current
+edit-start-date = start date
+edit-final-date = final date
+edit-years = years
+edit-months = months
+edit-weeks = weeks
+edit-days = days
+edit-start-amount = start amount
+edit-final-amount = final amount
+edit-cagr = CAGR
+edit-recalculate = recalculate
+edit-number-format = number format
+edit-currency = currency
+edit-rate-format = rate format
+edit-language = language
+edit-import = import
+
+## Settings
+dark-mode = DARK MODE
+dark-mode-switch = Dark mode
+mode-system = follow system
+scale = SCALE
+scale-zoom = zoom
+scale-text = text
+language = LANGUAGE
+number-format = NUMBER FORMAT
+currency = CURRENCY
+rate-precision = RATE PRECISION
+decimals = decimals
+significant = significant
+rate-unit = RATE UNIT
+
+## Sensitivity
+table = table
+sensitivity-title = Sensitivity
+sensitivity-from = from
+sensitivity-to = to
+sensitivity-step = step
+sensitivity-rates = CAGR
+sensitivity-years = Years
+
+## Simulation
+simulate = simulate
+simulation-title = Simulation
+simulation-expected = Expected return
+simulation-from-cagr = from CAGR
+simulation-volatility = Volatility
+simulation-fat-tails = Fat tails
+simulation-runs = Runs
+simulation-seed = Seed
+simulation-target = Target
+simulation-percentile = { $p } % end below
+simulation-probability = Chance of reaching { $target }: { $chance } %
+
+## Import
+import = import
+series-title = Price series
+series-file = CSV file
+series-load = load
+series-from = From
+series-to = To
+series-use = use these dates and prices
+series-risk-free = Risk-free rate
+series-window = Rolling years
+series-cagr = CAGR
+series-volatility = Volatility
+series-drawdown = Max drawdown
+series-sharpe = Sharpe ratio
+series-sortino = Sortino ratio
+series-best = Best rolling period
+series-worst = Worst rolling period
+series-windows = Windows
+series-median = Median
+series-rolling-none = No window fits in the selection, or only across gaps in the prices
+series-skipped = Skipped { $rows } rows without a date or price
+error-series-dates = No column of dates in a known format
+error-series-few = Needs at least two dated prices
+
+## Theme
+theme = THEME
+theme-edit = edit
+theme-title = Theme
+theme-accent = Accent
+theme-dark = Dark background and text
+theme-light = Light background and text
+theme-gradient = Gradient toward dark and light
+theme-shape = Shape
+shape-linear = linear
+shape-radial = radial
+theme-interpolation = Mixing
+mix-gamma = sRGB
+mix-linear = linear light
+mix-oklab = Oklab
+theme-cache = Gradient cache: { $textures } textures, { $kb } kB, { $hits } hits, { $misses } misses
+theme-rounding = Corner radius
+theme-spacing = Spacing
+theme-file = Theme file
+theme-import = import
+theme-export = export
+theme-reset = reset
+font-file = Font file
+font-load = load
+font-default = default
+
+## Shortcuts
+shortcuts-title = Shortcuts
+key-enter = Enter
+key-tab = Tab
+key-space = Space
+shortcut-recalculate = recalculate
+shortcut-undo = undo
+shortcut-redo = redo
+shortcut-dark-mode = dark mode
+shortcut-larger = zoom in
+shortcut-smaller = zoom out
+shortcut-reset-zoom = reset zoom
+shortcut-save = save
+shortcut-help = this list
+shortcut-next-field = next field
+shortcut-toggle = toggle switch
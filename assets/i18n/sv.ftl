@@ -0,0 +1,157 @@
+# Compounder, svenska
+
+## Calculator
+start-date = STARTDATUM
+final-date = SLUTDATUM
+years = år
+months = månader
+weeks = veckor
+days = dagar
+start-amount = STARTBELOPP
+final-amount = SLUTBELOPP
+cagr = CAGR
+cagr-bp = CAGR (BP)
+number-drag = Dra för att justera
+number-down = Minska
+number-up = Öka
+
+## Validation
+error-date = Ange ett datum som { $example }
+error-start-after-final = Startdatum är efter slutdatum
+error-final-before-start = Slutdatum är före startdatum
+error-amount = Ange ett belopp eller en beräkning
+error-rate = Ange en tillväxttakt eller en beräkning
+error-rate-range = Ange en tillväxttakt mellan { $low } och { $high }
+error-syntax = Oväntad inmatning vid position { $position }
+error-unknown-name = Okänt namn { $name }, använd A, B eller C
+error-division-by-zero = Division med noll
+error-too-large = Resultatet är för stort
+error-currency = Använd en symbol som $ eller en kod på högst tre bokstäver
+
+## History
+history = HISTORIK
+undo = ångra
+redo = gör om
+list = lista
+keys = tangenter
+history-title = Historik
+history-current = nuvarande
+edit-start-date = startdatum
+edit-final-date = slutdatum
+edit-years = år
+edit-months = månader
+edit-weeks = veckor
+edit-days = dagar
+edit-start-amount = startbelopp
+edit-final-amount = slutbelopp
+edit-cagr = CAGR
+edit-recalculate = omräkning
+edit-number-format = talformat
+edit-currency = valuta
+edit-rate-format = format för tillväxttakt
+edit-language = språk
+edit-import = import
+
+## Settings
+dark-mode = MÖRKT LÄGE
+dark-mode-switch = Mörkt läge
+mode-system = följ systemet
+scale = SKALA
+scale-zoom = zoom
+scale-text = text
+language = SPRÅK
+number-format = TALFORMAT
+currency = VALUTA
+rate-precision = PRECISION
+decimals = decimaler
+significant = värdesiffror
+rate-unit = ENHET
+
+## Sensitivity
+table = tabell
+sensitivity-title = Känslighet
+sensitivity-from = från
+sensitivity-to = till
+sensitivity-step = steg
+sensitivity-rates = CAGR
+sensitivity-years = År
+
+## Simulation
+simulate = simulera
+simulation-title = Simulering
+simulation-expected = Förväntad avkastning
+simulation-from-cagr = från CAGR
+simulation-volatility = Volatilitet
+simulation-fat-tails = Tjocka svansar
+simulation-runs = Körningar
+simulation-seed = Frö
+simulation-target = Mål
+simulation-percentile = { $p } % slutar under
+simulation-probability = Chans att nå { $target }: { $chance } %
+
+## Import
+import = importera
+series-title = Kursserie
+series-file = CSV-fil
+series-load = läs in
+series-from = Från
+series-to = Till
+series-use = använd dessa datum och kurser
+series-risk-free = Riskfri ränta
+series-window = Rullande år
+series-cagr = CAGR
+series-volatility = Volatilitet
+series-drawdown = Största nedgång
+series-sharpe = Sharpekvot
+series-sortino = Sortinokvot
+series-best = Bästa rullande period
+series-worst = Sämsta rullande period
+series-windows = Fönster
+series-median = Median
+series-rolling-none = Inget fönster ryms i urvalet, eller bara över luckor i kurserna
+series-skipped = Hoppade över { $rows } rader utan datum eller kurs
+error-series-dates = Ingen kolumn med datum i ett känt format
+error-series-few = Behöver minst två daterade kurser
+
+## Theme
+theme = TEMA
+theme-edit = ändra
+theme-title = Tema
+theme-accent = Accentfärg
+theme-dark = Mörk bakgrund och text
+theme-light = Ljus bakgrund och text
+theme-gradient = Toning mot mörk och ljus
+theme-shape = Form
+shape-linear = linjär
+shape-radial = radiell
+theme-interpolation = Blandning
+mix-gamma = sRGB
+mix-linear = linjärt ljus
+mix-oklab = Oklab
+theme-cache = Toningscache: { $textures } texturer, { $kb } kB, { $hits } träffar, { $misses } missar
+theme-rounding = Hörnradie
+theme-spacing = Avstånd
+theme-file = Temafil
+theme-import = importera
+theme-export = exportera
+theme-reset = återställ
+font-file = Typsnittsfil
+font-load = ladda
+font-default = standard
+
+## Shortcuts
+shortcuts-title = Kortkommandon
+key-enter = Enter
+key-tab = Tabb
+key-space = Mellanslag
+shortcut-recalculate = räkna om
+shortcut-undo = ångra
+shortcut-redo = gör om
+shortcut-dark-mode = mörkt läge
+shortcut-larger = zooma in
+shortcut-smaller = zooma ut
+shortcut-reset-zoom = återställ zoom
+shortcut-save = spara
+shortcut-help = denna lista
+shortcut-next-field = nästa fält
+shortcut-toggle = växla reglage
@@ -0,0 +1,278 @@
+#![deny(clippy::pedantic)]
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![deny(unused_must_use)]
+
+// Small recursive descent evaluator for numeric fields, like `1500*12`, `1e6` or `=A+5%`.
+// Grammar:
+//   sum     = product (('+' | '-') product)*
+//   product = unary (('*' | '/') unary)*
+//   unary   = '-' unary | power
+//   power   = primary ('^' unary)?
+//   primary = (number | name | '(' sum ')') '%'?
+// As on a calculator `a + b%` means `a * (1 + b/100)`; anywhere else `b%` is `b/100`.
+
+use crate::number::{self, NumberLocale};
+
+const MAX_DEPTH: usize = 100; // Nesting of brackets, signs and powers, well within the stack.
+
+// What went wrong, see `Compounder::explain_expression`. Positions count characters from zero.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Error
+{
+    Syntax(usize),
+    UnknownName(String),
+    DivisionByZero,
+    NotFinite
+}
+
+/// Evaluates `text` as an arithmetic expression, with an optional leading `=`.
+/// Names are matched case-insensitively against `names`.
+pub fn evaluate (text: &str, locale: NumberLocale, names: &[(&str, f64)]) -> Result<f64, Error> {
+    let text = text.trim();
+    let text = text.strip_prefix('=').unwrap_or(text);
+    let mut parser = Parser {
+        chars: text.chars().collect(),
+        at: 0,
+        depth: 0,
+        locale,
+        names
+    };
+    let (value, _) = parser.sum()?;
+    parser.skip();
+    if parser.at < parser.chars.len() {
+        return Err(Error::Syntax(parser.at));
+    }
+    if value.is_finite() { Ok(value) } else { Err(Error::NotFinite) }
+}
+
+struct Parser<'a>
+{
+    chars: Vec<char>,
+    at: usize,
+    depth: usize,
+    locale: NumberLocale,
+    names: &'a [(&'a str, f64)]
+}
+
+impl Parser<'_>
+{
+    fn skip (&mut self) {
+        while self.chars.get(self.at).is_some_and(|c| c.is_whitespace()) {
+            self.at += 1;
+        }
+    }
+
+    fn peek (&mut self) -> Option<char> {
+        self.skip();
+        self.chars.get(self.at).copied()
+    }
+
+    fn eat (&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.at += 1;
+            return true;
+        }
+        false
+    }
+
+    // Parses one level deeper, failing on input like `((((…` rather than overflowing the stack.
+    fn nested (&mut self, parse: fn(&mut Self) -> Result<(f64, bool), Error>) -> Result<(f64, bool), Error> {
+        if  self.depth >= MAX_DEPTH {
+            return Err(Error::Syntax(self.at));
+        }
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+
+    // The flag tells whether the value was written as a percentage.
+    fn sum (&mut self) -> Result<(f64, bool), Error> {
+        let (mut value, mut percent) = self.product()?;
+        loop {
+            let sign = if self.eat('+') { 1.0 } else if self.eat('-') { -1.0 } else { break };
+            let (rhs, rp) = self.product()?;
+            value = if rp { value * (1.0 + sign * rhs) } else { value + sign * rhs };
+            percent = false;
+        }
+        Ok((value, percent))
+    }
+
+    fn product (&mut self) -> Result<(f64, bool), Error> {
+        let (mut value, mut percent) = self.unary()?;
+        loop {
+            if self.eat('*') {
+                value *= self.unary()?.0;
+            } else if self.eat('/') {
+                let rhs = self.unary()?.0;
+                if rhs == 0.0 {
+                    return Err(Error::DivisionByZero);
+                }
+                value /= rhs;
+            } else {
+                break;
+            }
+            percent = false;
+        }
+        Ok((value, percent))
+    }
+
+    fn unary (&mut self) -> Result<(f64, bool), Error> {
+        if self.eat('-') {
+            let (value, percent) = self.nested(Self::unary)?;
+            return Ok((-value, percent));
+        }
+        if self.eat('+') {
+            return self.nested(Self::unary);
+        }
+        let (value, percent) = self.primary()?;
+        if self.eat('^') {
+            return Ok((value.powf(self.nested(Self::unary)?.0), false));
+        }
+        Ok((value, percent))
+    }
+
+    fn primary (&mut self) -> Result<(f64, bool), Error> {
+        let value = match self.peek() {
+            Some('(') => {
+                self.at += 1;
+                let (value, _) = self.nested(Self::sum)?;
+                if !self.eat(')') {
+                    return Err(Error::Syntax(self.at));
+                }
+                value
+            },
+            Some(c) if c.is_ascii_digit() || c == '.' || c == ',' => self.number()?,
+            Some(c) if c.is_alphabetic() => self.name()?,
+            _ => return Err(Error::Syntax(self.at))
+        };
+        if self.eat('%') {
+            return Ok((value / 100.0, true));
+        }
+        Ok((value, false))
+    }
+
+    // Whether a group of three digits follows at `at`, like in `1 500` with spaces for grouping.
+    fn group (&self, at: usize) -> bool {
+        self.chars.get(at) == Some(&self.locale.group())
+            && (1..=3).all(|i| self.chars.get(at + i).is_some_and(char::is_ascii_digit))
+            && !self.chars.get(at + 4).is_some_and(char::is_ascii_digit)
+    }
+
+    fn number (&mut self) -> Result<f64, Error> {
+        let start = self.at;
+        while self.chars.get(self.at).is_some_and(|c| c.is_ascii_digit() || *c == '.' || *c == ',') || self.group(self.at) {
+            self.at += 1;
+        }
+        let mut core: String = self.chars[start..self.at].iter().collect();
+        if self.chars.get(self.at).is_some_and(|c| *c == 'e' || *c == 'E') {
+            let mut end = self.at + 1;
+            if self.chars.get(end).is_some_and(|c| *c == '+' || *c == '-') {
+                end += 1;
+            }
+            if self.chars.get(end).is_some_and(char::is_ascii_digit) {
+                while self.chars.get(end).is_some_and(char::is_ascii_digit) {
+                    end += 1;
+                }
+                core = self.chars[start..end].iter().collect();
+                self.at = end;
+            }
+        }
+        let (core, exponent) = core.split_once(['e', 'E']).map_or((core.as_str(), ""), |(c, e)| (c, e));
+        let mut value = number::parse_number(core, self.locale).ok_or(Error::Syntax(start))?;
+        if !exponent.is_empty() {
+            value *= 10_f64.powi(exponent.parse::<i32>().map_err(|_| Error::Syntax(start))?);
+        }
+        let multiplier = match self.chars.get(self.at) {
+            Some('k' | 'K') => 1e3,
+            Some('m' | 'M') => 1e6,
+            Some('b' | 'B') => 1e9,
+            _ => return Ok(value)
+        };
+        if self.chars.get(self.at + 1).is_some_and(|c| c.is_alphanumeric()) {
+            return Ok(value);
+        }
+        self.at += 1;
+        Ok(value * multiplier)
+    }
+
+    fn name (&mut self) -> Result<f64, Error> {
+        let start = self.at;
+        while self.chars.get(self.at).is_some_and(|c| c.is_alphanumeric() || *c == '_') {
+            self.at += 1;
+        }
+        let name: String = self.chars[start..self.at].iter().collect();
+        self.names.iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(&name))
+            .map(|(_, v)| *v)
+            .ok_or(Error::UnknownName(name))
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn english (text: &str) -> Result<f64, Error> {
+        evaluate(text, NumberLocale::English, &[("A", 3.0)])
+    }
+
+    #[test]
+    fn precedence () {
+        assert_eq!(english("1+2*3"), Ok(7.0));
+        assert_eq!(english("(1+2)*3"), Ok(9.0));
+        assert_eq!(english("2^3^2"), Ok(512.0));
+        assert_eq!(english("12/2/3"), Ok(2.0));
+        assert_eq!(english("=a*2 - 1"), Ok(5.0));
+    }
+
+    #[test]
+    fn unary_minus () {
+        assert_eq!(english("-2^2"), Ok(-4.0));
+        assert_eq!(english("3--2"), Ok(5.0));
+        assert_eq!(english("-(1+2)*2"), Ok(-6.0));
+    }
+
+    #[test]
+    fn percentages () {
+        assert_eq!(english("50%"), Ok(0.5));
+        assert_eq!(english("200+25%"), Ok(250.0));
+        assert_eq!(english("200-25%"), Ok(150.0));
+        assert_eq!(english("200*25%"), Ok(50.0));
+    }
+
+    #[test]
+    fn errors () {
+        assert_eq!(english("1/0"), Err(Error::DivisionByZero));
+        assert_eq!(english("1/(2-2)"), Err(Error::DivisionByZero));
+        assert_eq!(english("1+*2"), Err(Error::Syntax(2)));
+        assert_eq!(english("(1+2"), Err(Error::Syntax(4)));
+        assert_eq!(english("x+1"), Err(Error::UnknownName(String::from("x"))));
+        assert_eq!(english("10^400"), Err(Error::NotFinite));
+    }
+
+    #[test]
+    fn nesting_is_limited () {
+        let deep = |open: &str, n: usize| format!("{}1{}", open.repeat(n), ")".repeat(if open == "(" { n } else { 0 }));
+        assert_eq!(english(&deep("(", 50)), Ok(1.0));
+        assert_eq!(english(&deep("-", 50)), Ok(1.0));
+        assert!(matches!(english(&deep("(", 100_000)), Err(Error::Syntax(at)) if at > MAX_DEPTH));
+        assert!(matches!(english(&deep("-", 100_000)), Err(Error::Syntax(at)) if at > MAX_DEPTH));
+        assert!(matches!(english(&"2^".repeat(100_000)), Err(Error::Syntax(_))));
+    }
+
+    #[test]
+    fn locale_separators () {
+        assert_eq!(english("1,500*2"), Ok(3000.0));
+        assert_eq!(english("1.5k"), Ok(1500.0));
+        assert_eq!(english("2e3"), Ok(2000.0));
+        assert_eq!(evaluate("1.500*2", NumberLocale::German, &[]), Ok(3000.0));
+        assert_eq!(evaluate("1,5*2", NumberLocale::German, &[]), Ok(3.0));
+        assert_eq!(evaluate("1 500*2", NumberLocale::Swedish, &[]), Ok(3000.0));
+        assert_eq!(evaluate("1 500 000 / 2", NumberLocale::Swedish, &[]), Ok(750_000.0));
+        assert_eq!(evaluate("5 12", NumberLocale::Swedish, &[]), Err(Error::Syntax(2)));
+    }
+}
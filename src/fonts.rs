@@ -0,0 +1,54 @@
+#![deny(clippy::pedantic)]
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![deny(unused_must_use)]
+
+use std::sync::Arc;
+
+use eframe::egui::{
+    FontData,
+    FontDefinitions,
+    FontFamily
+};
+
+const SANS: &str = "Sans Font";
+// DejaVu Sans Bold stands in for Inter's bold and semibold, which aren't bundled, so emphasized
+// text is in another typeface than the rest. Replace it with Inter-Bold.ttf once that is in assets.
+const BOLD: &str = "Sans Bold";
+const MONO: &str = "Sans Mono";
+const USER: &str = "User Font";
+
+/// The family used for emphasized text, since egui only has one weight per family.
+pub fn bold () -> FontFamily {
+    FontFamily::Name(BOLD.into())
+}
+
+/// Reads a TTF or OTF file and checks that it parses, as egui panics on fonts it can't read.
+pub fn load (path: &str) -> Result<Vec<u8>, String> {
+    let bytes = std::fs::read(path).map_err(|e| e.to_string())?;
+    ab_glyph::FontRef::try_from_slice(&bytes).map_err(|e| e.to_string())?;
+    Ok(bytes)
+}
+
+/// The bundled fonts, with an optional user font in front of the proportional ones.
+/// Numbers are shown in the monospace family, whose figures all have the same width.
+pub fn definitions (user: Option<Vec<u8>>) -> FontDefinitions {
+    let mut fd = FontDefinitions::default();
+    fd.font_data.insert(SANS.to_string(), Arc::new(FontData::from_static(include_bytes!("../assets/Inter-Regular.ttf"))));
+    fd.font_data.insert(BOLD.to_string(), Arc::new(FontData::from_static(include_bytes!("../assets/DejaVuSans-Bold.ttf"))));
+    fd.font_data.insert(MONO.to_string(), Arc::new(FontData::from_static(include_bytes!("../assets/DejaVuSansMono.ttf"))));
+    let mut proportional = fd.families.get(&FontFamily::Proportional).cloned().unwrap_or_default();
+    proportional.insert(0, SANS.to_string());
+    if let Some(bytes) = user {
+        fd.font_data.insert(USER.to_string(), Arc::new(FontData::from_owned(bytes)));
+        proportional.insert(0, USER.to_string());
+    }
+    proportional.push(MONO.to_string()); // The only bundled font with symbols like ☀, as eframe comes without emoji here.
+    let mut heavy = vec![BOLD.to_string()];
+    heavy.extend(proportional.iter().cloned()); // For the glyphs the bold font lacks.
+    fd.families.insert(FontFamily::Proportional, proportional);
+    fd.families.insert(bold(), heavy);
+    fd.families.entry(FontFamily::Monospace).or_default().insert(0, MONO.to_string());
+    fd
+}
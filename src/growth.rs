@@ -0,0 +1,160 @@
+#![deny(clippy::pedantic)]
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![deny(unused_must_use)]
+
+// Growth rates and risk measures of dated prices, for the calculator and imported series.
+
+use chrono::NaiveDate;
+
+// An annualized rate in percent, with the dates it runs between.
+pub type Period = (f64, NaiveDate, NaiveDate);
+
+// Rates and ratios are yearly, rates and the drawdown in percent.
+pub struct Metrics
+{
+    pub cagr: f64,
+    pub volatility: f64,
+    pub drawdown: Period,
+    pub sharpe: Option<f64>,
+    pub sortino: Option<f64>
+}
+
+/// The years between two dates, counting a year as 365.25 days.
+pub fn years (sd: NaiveDate, fd: NaiveDate) -> f64 {
+    f64::from(i32::try_from((fd - sd).num_days()).unwrap_or(0)) / 365.25
+}
+
+/// The compound annual growth rate in percent, from `sv` to `fv` in `years`.
+pub fn cagr (sv: f64, fv: f64, years: f64) -> f64 {
+    ((fv / sv).powf(1.0 / years) - 1.0) * 100.0
+}
+
+/// The risk measures are based on the returns between points, so the number of points
+/// per year is taken from the series itself, whether its prices are daily or monthly.
+#[allow(clippy::cast_precision_loss)]
+pub fn metrics (points: &[(NaiveDate, f64)], risk_free: f64) -> Option<Metrics> {
+    let (&(sd, sv), &(fd, fv)) = (points.first()?, points.last()?);
+    let span = years(sd, fd);
+    if  points.len() < 2 || span <= 0.0 {
+        return None;
+    }
+    let returns: Vec<f64> = points.windows(2).map(|w| w[1].1 / w[0].1 - 1.0).collect();
+    let n = returns.len() as f64;
+    let per_year = n / span;
+    let mean = returns.iter().sum::<f64>() / n;
+    let variance = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (n - 1.0).max(1.0);
+    let volatility = (variance * per_year).sqrt();
+    let rf = risk_free / 100.0 / per_year;
+    let excess = (mean - rf) * per_year;
+    let downside = (returns.iter().map(|r| (r - rf).min(0.0).powi(2)).sum::<f64>() / n * per_year).sqrt();
+    let mut peak = (sd, sv);
+    let mut drawdown = (0.0, sd, sd);
+    for &(day, price) in points {
+        if  price > peak.1 {
+            peak = (day, price);
+        }
+        let fall = (1.0 - price / peak.1) * 100.0;
+        if  fall > drawdown.0 {
+            drawdown = (fall, peak.0, day);
+        }
+    }
+    Some(Metrics {
+        cagr: cagr(sv, fv, span),
+        volatility: volatility * 100.0,
+        drawdown,
+        sharpe: (volatility > 0.0).then(|| excess / volatility),
+        sortino: (downside > 0.0).then(|| excess / downside)
+    })
+}
+
+/// Every period from a point to the first point at least `window` years later. Periods that
+/// run across a gap in the prices, to more than half a window beyond it, are left out,
+/// as they would be counted as windows of a length they don't have.
+#[allow(clippy::cast_possible_truncation)]
+pub fn rolling (points: &[(NaiveDate, f64)], window: f64) -> Vec<Period> {
+    let length = chrono::TimeDelta::days(((window * 365.25).round() as i64).max(1));
+    let longest = length * 3 / 2;
+    let mut periods = Vec::new();
+    let mut j = 0;
+    for &(sd, sv) in points {
+        while points.get(j).is_some_and(|(day, _)| *day < sd + length) {
+            j += 1;
+        }
+        let Some(&(fd, fv)) = points.get(j) else {
+            break;
+        };
+        if  fd - sd <= longest {
+            periods.push((cagr(sv, fv, years(sd, fd)), sd, fd));
+        }
+    }
+    periods
+}
+
+/// The middle rate of the periods, which need not be sorted.
+pub fn median (periods: &[Period]) -> Option<f64> {
+    let mut rates: Vec<f64> = periods.iter().map(|(cc, _, _)| *cc).collect();
+    rates.sort_by(f64::total_cmp);
+    let mid = rates.len() / 2;
+    match rates.len() {
+        0 => None,
+        n if n % 2 == 0 => Some(rates[mid - 1].midpoint(rates[mid])),
+        _ => Some(rates[mid])
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn day (y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap_or_default()
+    }
+
+    #[test]
+    fn cagr_over_whole_years () {
+        assert!((cagr(100.0, 121.0, 2.0) - 10.0).abs() < 1e-9);
+        assert!((cagr(100.0, 100.0, 5.0)).abs() < 1e-9);
+        assert!((years(day(2020, 1, 1), day(2024, 1, 1)) - 4.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn rolling_window_longer_than_series () {
+        let points = [(day(2020, 1, 1), 100.0), (day(2020, 7, 1), 110.0), (day(2021, 1, 1), 120.0)];
+        assert!(rolling(&points, 2.0).is_empty());
+        assert!(rolling(&points[..1], 0.1).is_empty());
+        assert!(rolling(&[], 1.0).is_empty());
+    }
+
+    #[test]
+    fn rolling_across_gaps () {
+        // Nothing between March 2021 and 2023, so periods that can't end before the gap are left out.
+        let points = [
+            (day(2020, 1, 1), 100.0),
+            (day(2020, 6, 1), 100.0),
+            (day(2021, 3, 1), 110.0),
+            (day(2023, 1, 1), 121.0),
+            (day(2024, 1, 1), 133.1)
+        ];
+        let periods = rolling(&points, 1.0);
+        let ends: Vec<(NaiveDate, NaiveDate)> = periods.iter().map(|(_, sd, fd)| (*sd, *fd)).collect();
+        assert_eq!(ends, vec![
+            (day(2020, 1, 1), day(2021, 3, 1)),
+            (day(2023, 1, 1), day(2024, 1, 1))
+        ]);
+        // Rates are annualized over the actual span, not the window.
+        assert!((periods[0].0 - cagr(100.0, 110.0, years(day(2020, 1, 1), day(2021, 3, 1)))).abs() < 1e-9);
+        assert!(periods[0].0 < 10.0);
+        assert!(rolling(&points[1..4], 1.0).is_empty());
+    }
+
+    #[test]
+    fn median_of_odd_and_even () {
+        let period = |cc: f64| (cc, day(2020, 1, 1), day(2021, 1, 1));
+        assert_eq!(median(&[]), None);
+        assert_eq!(median(&[period(3.0), period(1.0), period(2.0)]), Some(2.0));
+        assert_eq!(median(&[period(4.0), period(1.0), period(2.0), period(3.0)]), Some(2.5));
+    }
+}
@@ -0,0 +1,73 @@
+#![deny(clippy::pedantic)]
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![deny(unused_must_use)]
+
+const LIMIT: usize = 100;
+
+// Each entry holds the label of an edit and the state on the far side of it,
+// so undoing swaps the present state with the last entry in `past`.
+pub struct History<T>
+{
+    past: Vec<(&'static str, T)>,
+    future: Vec<(&'static str, T)>,
+    present: Option<T>
+}
+
+impl<T> Default for History<T>
+{
+    fn default() -> Self {
+        Self {
+            past: Vec::new(),
+            future: Vec::new(),
+            present: None
+        }
+    }
+}
+
+impl<T: Clone + PartialEq> History<T>
+{
+    pub fn reset (&mut self, state: T) {
+        self.past.clear();
+        self.future.clear();
+        self.present = Some(state);
+    }
+
+    pub fn record (&mut self, label: &'static str, state: T) {
+        if  self.present.as_ref() == Some(&state) {
+            return;
+        }
+        if let Some(present) = self.present.replace(state) {
+            self.past.push((label, present));
+            self.future.clear();
+            if  self.past.len() > LIMIT {
+                self.past.remove(0);
+            }
+        }
+    }
+
+    pub fn undo (&mut self) -> Option<T> {
+        let (label, state) = self.past.pop()?;
+        if let Some(present) = self.present.replace(state.clone()) {
+            self.future.push((label, present));
+        }
+        Some(state)
+    }
+
+    pub fn redo (&mut self) -> Option<T> {
+        let (label, state) = self.future.pop()?;
+        if let Some(present) = self.present.replace(state.clone()) {
+            self.past.push((label, present));
+        }
+        Some(state)
+    }
+
+    pub fn past (&self) -> impl DoubleEndedIterator<Item = &'static str> + '_ {
+        self.past.iter().map(|(label, _)| *label)
+    }
+
+    pub fn future (&self) -> impl DoubleEndedIterator<Item = &'static str> + '_ {
+        self.future.iter().map(|(label, _)| *label)
+    }
+}
@@ -0,0 +1,117 @@
+#![deny(clippy::pedantic)]
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![deny(unused_must_use)]
+
+use std::collections::HashMap;
+
+use crate::number::NumberLocale;
+
+#[derive(serde::Deserialize, serde::Serialize, PartialEq, Copy, Clone)]
+pub enum Language
+{
+    English,
+    Swedish,
+    German
+}
+
+impl Language
+{
+    pub const ALL: [Language; 3] = [Language::English, Language::Swedish, Language::German];
+
+    pub const fn name (self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Swedish => "Svenska",
+            Language::German  => "Deutsch"
+        }
+    }
+
+    pub const fn date_format (self) -> &'static str {
+        match self {
+            Language::English | Language::Swedish => "%Y-%m-%d",
+            Language::German  => "%d.%m.%Y"
+        }
+    }
+
+    /// The date format as an input mask, see [`compounder_widgets::ErrorField::mask`].
+    pub const fn date_mask (self) -> &'static str {
+        match self {
+            Language::English | Language::Swedish => "YYYY-MM-DD",
+            Language::German  => "DD.MM.YYYY"
+        }
+    }
+
+    pub const fn number_locale (self) -> NumberLocale {
+        match self {
+            Language::English => NumberLocale::English,
+            Language::Swedish => NumberLocale::Swedish,
+            Language::German  => NumberLocale::German
+        }
+    }
+
+    const fn source (self) -> &'static str {
+        match self {
+            Language::English => include_str!("../assets/i18n/en.ftl"),
+            Language::Swedish => include_str!("../assets/i18n/sv.ftl"),
+            Language::German  => include_str!("../assets/i18n/de.ftl")
+        }
+    }
+}
+
+/// Translated UI strings, read from the bundled Fluent files. Only simple messages
+/// and `{ $variable }` placeables are supported. Missing messages fall back to English.
+pub struct Catalog(HashMap<String, String>);
+
+impl Default for Catalog
+{
+    fn default() -> Self {
+        Self::new(Language::English)
+    }
+}
+
+impl Catalog
+{
+    pub fn new (language: Language) -> Self {
+        let mut messages = HashMap::new();
+        for source in [Language::English.source(), language.source()] {
+            parse(source, &mut messages);
+        }
+        Self(messages)
+    }
+
+    pub fn get<'a> (&'a self, key: &'a str) -> &'a str {
+        self.0.get(key).map_or(key, String::as_str)
+    }
+
+    pub fn format (&self, key: &str, args: &[(&str, &str)]) -> String {
+        let mut text = self.get(key).to_string();
+        for (name, value) in args {
+            text = text.replace(&format!("{{ ${name} }}"), value);
+        }
+        text
+    }
+}
+
+fn parse (source: &str, messages: &mut HashMap<String, String>) {
+    let mut last: Option<String> = None;
+    for line in source.lines() {
+        if line.trim().is_empty() || line.trim_start().starts_with('#') {
+            last = None;
+            continue;
+        }
+        if line.starts_with(char::is_whitespace) { // Indented lines continue the previous message.
+            if let Some(text) = last.as_ref().and_then(|key| messages.get_mut(key)) {
+                text.push(' ');
+                text.push_str(line.trim());
+            }
+            continue;
+        }
+        if let Some((key, text)) = line.split_once('=') {
+            let key = key.trim().to_string();
+            messages.insert(key.clone(), text.trim().to_string());
+            last = Some(key);
+        }
+    }
+}
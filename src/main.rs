@@ -12,9 +12,27 @@
 // #![deny(clippy::panic)]
 // #![deny(unused_must_use)]
 
-const GUI_SIZE: egui::Vec2 = egui::Vec2::new(400.0, 390.0);
-const ACCENT_COLOR: egui::Color32 = egui::Color32::from_rgb(170, 0, 204);
-const DATEFORMAT: &str = "%Y-%m-%d";
+const GUI_SIZE: egui::Vec2 = egui::Vec2::new(400.0, 520.0); // Initial size, the window remembers its own afterwards.
+const MIN_SIZE: egui::Vec2 = egui::Vec2::new(280.0, 240.0);
+const NARROW_WIDTH: f32 = 380.0; // Below this the rows are stacked into one column.
+const WIDE_WIDTH: f32 = 720.0;   // Above this the history is shown in a side panel.
+
+const SHORTCUT_UNDO:    egui::KeyboardShortcut = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::Z);
+const SHORTCUT_REDO:    egui::KeyboardShortcut = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND.plus(egui::Modifiers::SHIFT), egui::Key::Z);
+const SHORTCUT_DARK:    egui::KeyboardShortcut = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::D);
+const SHORTCUT_SAVE:    egui::KeyboardShortcut = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::S);
+const SHORTCUT_HELP:    egui::KeyboardShortcut = egui::KeyboardShortcut::new(egui::Modifiers::NONE,    egui::Key::F1);
+const SHORTCUT_LARGER:  egui::KeyboardShortcut = egui::gui_zoom::kb_shortcuts::ZOOM_IN;
+const SHORTCUT_LARGER2: egui::KeyboardShortcut = egui::gui_zoom::kb_shortcuts::ZOOM_IN_SECONDARY;
+const SHORTCUT_SMALLER: egui::KeyboardShortcut = egui::gui_zoom::kb_shortcuts::ZOOM_OUT;
+const SHORTCUT_RESET:   egui::KeyboardShortcut = egui::gui_zoom::kb_shortcuts::ZOOM_RESET;
+
+const ZOOM_RANGE: std::ops::RangeInclusive<f32> = 0.5..=3.0;
+const ZOOM_STEP: f32 = 1.1;
+const TEXT_RANGE: std::ops::RangeInclusive<f32> = 0.75..=2.0;
+const MAX_YEARS: u16 = 200; // Larger spans can still be typed as months, weeks or days.
+const AMOUNT_RANGE: std::ops::RangeInclusive<f64> = 0.01..=1e15; // Beyond this the cents are lost.
+const RATE_RANGE: std::ops::RangeInclusive<f64> = -99.9..=1e4;   // In percent, at -100 % nothing is left to compound.
 
 use chrono::NaiveDate;
 use eframe::egui;
@@ -23,66 +41,324 @@ use eframe:: {
     Frame
 };
 
-mod switch;
-mod errorfield;
+mod number;
+mod growth;
+mod expression;
+mod history;
+mod language;
+mod theme;
+mod fonts;
+mod sensitivity;
+mod simulation;
+mod series;
 
-use switch::Switch;
-use errorfield::ErrorField;
+use compounder_widgets::{
+    gradient::{
+        self,
+        Gradient,
+        Interpolation,
+        Shape,
+        TextureManager
+    },
+    ErrorField,
+    NumberField,
+    Switch
+};
+use history::History;
+use language::{
+    Catalog,
+    Language
+};
+use theme::Theme;
+use sensitivity::Sensitivity;
+use simulation::Simulation;
+use series::{
+    ReadError,
+    Series
+};
+use number::{
+    NumberLocale,
+    RatePrecision,
+    RateUnit
+};
 
 #[derive(serde::Deserialize, serde::Serialize, PartialEq, Copy, Clone)]
 enum InterfaceSize 
 {
-    Small,
-    Medium,
-    Large
+    Small,  // The three presets are only read from older stored state,
+    Medium, // and are turned into the matching zoom factor at startup.
+    Large,
+    Zoom(f32)
+}
+
+impl InterfaceSize
+{
+    const fn factor (self) -> f32 {
+        match self {
+            InterfaceSize::Small   => 1.0,
+            InterfaceSize::Medium  => 1.3,
+            InterfaceSize::Large   => 1.7,
+            InterfaceSize::Zoom(z) => z
+        }
+    }
 }
 
 #[derive(serde::Deserialize, serde::Serialize, PartialEq, Copy, Clone)]
 enum InterfaceMode
 {
     Dark,
-    Light
+    Light,
+    System
+}
+
+// Why the text of a field can't be used, see `Compounder::explain`.
+#[derive(PartialEq, Copy, Clone)]
+enum InputError
+{
+    Date,
+    StartAfterFinal,
+    FinalBeforeStart,
+    Amount,
+    Rate,
+    RateRange
+}
+
+// The part of the state covered by undo and redo.
+#[derive(PartialEq, Clone)]
+struct Snapshot
+{
+    start_date: String,
+    final_date: String,
+    years: u16,
+    months: u16,
+    weeks: u16,
+    days: u16,
+    start_amount: String,
+    final_amount: String,
+    cagr: String,
+    number_locale: NumberLocale,
+    currency: String,
+    rate_precision: RatePrecision,
+    rate_unit: RateUnit,
+    language: Language
 }
 
 #[derive(serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+#[allow(clippy::struct_excessive_bools)] // One for each window that can be opened.
 struct Compounder 
 {
     start_date: String,
     final_date: String,
-    years: u8,
-    months: u8,
-    weeks: u8,
-    days: u8,
+    years: u16,
+    months: u16,
+    weeks: u16,
+    days: u16,
     start_amount: String,
     final_amount: String,
     cagr: String,
     ui_size: InterfaceSize,
-    ui_mode: InterfaceMode
+    text_scale: f32,
+    ui_mode: InterfaceMode,
+    number_locale: NumberLocale,
+    currency: String,
+    rate_precision: RatePrecision,
+    rate_unit: RateUnit,
+    language: Language,
+    theme: Theme,
+    theme_path: String,
+    sensitivity: Sensitivity,
+    simulation: Simulation,
+    series: Series,
+    font_path: String,
+    #[serde(skip)]
+    font_status: String,
+    #[serde(skip)]
+    theme_status: String,
+    #[serde(skip)]
+    series_status: String,
+    #[serde(skip)]
+    show_theme: bool,
+    #[serde(skip)]
+    textures: TextureManager,
+    #[serde(skip)]
+    catalog: Catalog,
+    #[serde(skip)]
+    history: History<Snapshot>,
+    #[serde(skip)]
+    edited: Option<&'static str>,
+    #[serde(skip)]
+    show_history: bool,
+    #[serde(skip)]
+    show_shortcuts: bool,
+    #[serde(skip)]
+    show_sensitivity: bool,
+    #[serde(skip)]
+    show_simulation: bool,
+    #[serde(skip)]
+    show_series: bool
 }
 
 impl Compounder 
 {
     fn new (context: &eframe::CreationContext<'_>) -> Self {
-        let cc: Compounder = if let Some(ps) = context.storage { eframe::get_value(ps, eframe::APP_KEY).unwrap_or_default() } else { Compounder::default() };
+        let mut cc: Compounder = if let Some(ps) = context.storage { eframe::get_value(ps, eframe::APP_KEY).unwrap_or_default() } else { Compounder::default() };
         // egui_extras::install_image_loaders(&cc.egui_ctx);
-        Self::set_fonts(&context.egui_ctx);
-        Self::set_style(&context.egui_ctx, cc.ui_mode);
+        cc.font_status = Self::set_fonts(&context.egui_ctx, &cc.font_path);
+        Self::set_style(&context.egui_ctx, cc.ui_mode, &cc.theme);
+        context.egui_ctx.options_mut(|writer| writer.zoom_with_keyboard = false); // The zoom shortcuts are handled here, so the setting follows them.
+        let zf = cc.ui_size.factor().clamp(*ZOOM_RANGE.start(), *ZOOM_RANGE.end());
+        cc.ui_size = InterfaceSize::Zoom(zf);
+        context.egui_ctx.options_mut(|writer| writer.zoom_factor = zf);
+        Self::set_text_scale(&context.egui_ctx, cc.text_scale);
+        cc.catalog = Catalog::new(cc.language);
+        cc.history.reset(cc.snapshot());
         cc
     }
 
-    fn resize (&mut self, context: &egui::Context, size: InterfaceSize) {
-        if  self.ui_size == size {
+    fn snapshot (&self) -> Snapshot {
+        Snapshot {
+            start_date: self.start_date.clone(),
+            final_date: self.final_date.clone(),
+            years: self.years,
+            months: self.months,
+            weeks: self.weeks,
+            days: self.days,
+            start_amount: self.start_amount.clone(),
+            final_amount: self.final_amount.clone(),
+            cagr: self.cagr.clone(),
+            number_locale: self.number_locale,
+            currency: self.currency.clone(),
+            rate_precision: self.rate_precision,
+            rate_unit: self.rate_unit,
+            language: self.language
+        }
+    }
+
+    fn restore (&mut self, snapshot: Snapshot) {
+        self.start_date = snapshot.start_date;
+        self.final_date = snapshot.final_date;
+        self.years  = snapshot.years;
+        self.months = snapshot.months;
+        self.weeks  = snapshot.weeks;
+        self.days   = snapshot.days;
+        self.start_amount = snapshot.start_amount;
+        self.final_amount = snapshot.final_amount;
+        self.cagr = snapshot.cagr;
+        self.number_locale = snapshot.number_locale;
+        self.currency = snapshot.currency;
+        self.rate_precision = snapshot.rate_precision;
+        self.rate_unit = snapshot.rate_unit;
+        if  self.language != snapshot.language {
+            self.language = snapshot.language;
+            self.catalog = Catalog::new(snapshot.language);
+        }
+    }
+
+    fn undo (&mut self, steps: usize) {
+        for _ in 0..steps {
+            if let Some(snapshot) = self.history.undo() {
+                self.restore(snapshot);
+            }
+        }
+    }
+
+    fn redo (&mut self, steps: usize) {
+        for _ in 0..steps {
+            if let Some(snapshot) = self.history.redo() {
+                self.restore(snapshot);
+            }
+        }
+    }
+
+    // Commits the pending edit once the pointer is released, so a whole slider drag becomes one step.
+    fn record (&mut self, context: &egui::Context) {
+        if  context.input(|i| i.pointer.any_down()) {
             return;
         }
-        self.ui_size = size;
-        let zf = match size {
-            InterfaceSize::Small  => 1.0,
-            InterfaceSize::Medium => 1.3,
-            InterfaceSize::Large  => 1.7
-        };
+        if let Some(label) = self.edited.take() {
+            self.history.record(label, self.snapshot());
+        }
+    }
+
+    fn handle_shortcuts (&mut self, context: &egui::Context, frame: &mut Frame) {
+        let pressed = |shortcut: &egui::KeyboardShortcut| context.input_mut(|i| i.consume_shortcut(shortcut));
+        if pressed(&SHORTCUT_DARK) {
+            self.flip_mode(context);
+        }
+        if pressed(&SHORTCUT_LARGER) || pressed(&SHORTCUT_LARGER2) {
+            self.resize(context, self.ui_size.factor() * ZOOM_STEP);
+        }
+        if pressed(&SHORTCUT_SMALLER) {
+            self.resize(context, self.ui_size.factor() / ZOOM_STEP);
+        }
+        if pressed(&SHORTCUT_RESET) {
+            self.resize(context, 1.0);
+        }
+        let zoom = context.input(egui::InputState::zoom_delta); // Ctrl+scroll, or pinch on a touchpad.
+        if  (zoom - 1.0).abs() > f32::EPSILON {
+            self.resize(context, self.ui_size.factor() * zoom);
+        }
+        if pressed(&SHORTCUT_SAVE) {
+            if let Some(storage) = frame.storage_mut() {
+                self.save(storage);
+                storage.flush();
+            }
+        }
+        if pressed(&SHORTCUT_HELP) {
+            self.show_shortcuts = !self.show_shortcuts;
+        }
+        if  context.wants_keyboard_input() { // Leave enter and ctrl+z to the focused text field.
+            return;
+        }
+        if pressed(&SHORTCUT_REDO) {
+            self.redo(1);
+        } else if pressed(&SHORTCUT_UNDO) {
+            self.undo(1);
+        }
+        if context.memory(|m| m.focused().is_none()) && context.input(|i| i.key_pressed(egui::Key::Enter)) {
+            self.edited = Some("edit-recalculate");
+            self.redo_amounts();
+        }
+    }
+
+    // Scales everything, widgets and text alike.
+    fn resize (&mut self, context: &egui::Context, zf: f32) {
+        let zf = zf.clamp(*ZOOM_RANGE.start(), *ZOOM_RANGE.end());
+        if  self.ui_size == InterfaceSize::Zoom(zf) {
+            return;
+        }
+        self.ui_size = InterfaceSize::Zoom(zf);
         // context.set_zoom_factor(zf); // Strange things happen when zoom is set through method.
         context.options_mut(|writer| writer.zoom_factor = zf);
-        context.send_viewport_cmd(egui::ViewportCommand::InnerSize(GUI_SIZE)); // Hack to make gui resize.
+    }
+
+    // Scales only the text, on top of the zoom.
+    fn retext (&mut self, context: &egui::Context, scale: f32) {
+        if  (self.text_scale - scale).abs() < f32::EPSILON {
+            return;
+        }
+        self.text_scale = scale;
+        Self::set_text_scale(context, scale);
+    }
+
+    fn set_text_scale (context: &egui::Context, scale: f32) {
+        let base = egui::Style::default().text_styles;
+        context.all_styles_mut(|style| {
+            for (ts, font) in &mut style.text_styles {
+                if let Some(bf) = base.get(ts) {
+                    font.size = bf.size * scale;
+                }
+            }
+        });
+    }
+
+    // Switches to the opposite of what is shown, which also leaves system mode.
+    fn flip_mode (&mut self, context: &egui::Context) {
+        match context.theme() {
+            egui::Theme::Dark  => self.remode(context, InterfaceMode::Light),
+            egui::Theme::Light => self.remode(context, InterfaceMode::Dark)
+        }
     }
 
     fn remode (&mut self, context: &egui::Context, mode: InterfaceMode) {
@@ -90,13 +366,81 @@ impl Compounder
             return;
         }
         self.ui_mode = mode;
-        Self::set_style(context, mode);
+        Self::set_style(context, mode, &self.theme);
+    }
+
+    fn relanguage (&mut self, language: Language) {
+        if  self.language == language {
+            return;
+        }
+        let sd = self.parse_date(&self.start_date);
+        let fd = self.parse_date(&self.final_date);
+        self.language = language;
+        self.catalog = Catalog::new(language);
+        if let Ok(sd) = sd {
+            self.start_date = sd.format(language.date_format()).to_string();
+        }
+        if let Ok(fd) = fd {
+            self.final_date = fd.format(language.date_format()).to_string();
+        }
+        self.relocale(language.number_locale());
+        self.edited = Some("edit-language");
+    }
+
+    fn relocale (&mut self, locale: NumberLocale) {
+        if  self.number_locale == locale {
+            return;
+        }
+        self.edited = Some("edit-number-format");
+        self.reformat(locale);
+    }
+
+    fn reformat (&mut self, locale: NumberLocale) {
+        let sv = number::parse_amount(&self.start_amount, self.number_locale);
+        let fv = number::parse_amount(&self.final_amount, self.number_locale);
+        let tv = number::parse_amount(&self.simulation.target, self.number_locale);
+        let cc = number::parse_rate(&self.cagr, self.number_locale, self.rate_unit);
+        self.number_locale = locale;
+        if let Some(cc) = cc {
+            self.cagr = number::format_rate(cc, locale, self.rate_unit, self.rate_precision);
+        }
+        if let Some((sv, _)) = sv {
+            self.start_amount = number::format_amount(sv, locale, &self.currency);
+        }
+        if let Some((fv, _)) = fv {
+            self.final_amount = number::format_amount(fv, locale, &self.currency);
+        }
+        if let Some((tv, _)) = tv {
+            self.simulation.target = number::format_amount(tv, locale, &self.currency);
+        }
+    }
+
+    fn rerate (&mut self, unit: RateUnit, precision: RatePrecision) {
+        if  self.rate_unit == unit && self.rate_precision == precision {
+            return;
+        }
+        self.edited = Some("edit-rate-format");
+        let cc = number::parse_rate(&self.cagr, self.number_locale, self.rate_unit);
+        self.rate_unit = unit;
+        self.rate_precision = precision;
+        if let Some(cc) = cc {
+            self.cagr = number::format_rate(cc, self.number_locale, unit, precision);
+        }
+        self.redo_cagr();
+    }
+
+    fn retheme (&mut self, context: &egui::Context, theme: Theme) {
+        if  self.theme == theme {
+            return;
+        }
+        self.theme = theme;
+        Self::set_style(context, self.ui_mode, &self.theme);
     }
 
-    fn get_frame (&mut self) -> egui::Frame {
-        let cb = match self.ui_mode {
-            InterfaceMode::Dark  => egui::Color32::from_rgb( 20,  20,  20),
-            InterfaceMode::Light => egui::Color32::from_rgb(250, 250, 250)
+    fn get_frame (&self, context: &egui::Context) -> egui::Frame {
+        let cb = match context.theme() {
+            egui::Theme::Dark  => self.theme.dark_background,
+            egui::Theme::Light => self.theme.light_background
         };
         egui::Frame {
             inner_margin: egui::Margin::same(24.0),
@@ -105,57 +449,813 @@ impl Compounder
         }
     }
 
-    fn set_fonts (context: &egui::Context) {
-        let fontname = "Sans Font";
-        let mut font = egui::FontDefinitions::default();
-        font.font_data.insert(fontname.to_string(), std::sync::Arc::new(egui::FontData::from_static(include_bytes!("../assets/Inter-Regular.ttf"))));
-        if let Some(p) = font.families.get_mut(&egui::FontFamily::Proportional) {
-            p.insert(0, fontname.to_string());
-            context.set_fonts(font);
+    // Covers the whole panel, margins included, underneath what the panel adds after it.
+    fn paint_backdrop (&mut self, ui: &egui::Ui) {
+        if !self.theme.gradient {
+            return;
+        }
+        let (top, bottom) = match ui.ctx().theme() {
+            egui::Theme::Dark  => (self.theme.dark_background,  self.theme.dark_gradient),
+            egui::Theme::Light => (self.theme.light_background, self.theme.light_gradient)
+        };
+        let backdrop = Gradient::new(vec![(0.0, top), (1.0, bottom)], self.theme.gradient_interpolation, self.theme.gradient_shape);
+        let texture = self.textures.get(ui.ctx(), &backdrop);
+        gradient::paint(ui.painter(), ui.clip_rect(), &backdrop, texture);
+    }
+
+    // Returns why the user font couldn't be used, the bundled fonts are set regardless.
+    fn set_fonts (context: &egui::Context, path: &str) -> String {
+        let (user, status) = if path.is_empty() { (None, String::new()) } else {
+            match fonts::load(path) {
+                Ok(bytes) => (Some(bytes), String::new()),
+                Err(e) => (None, e)
+            }
         };
+        context.set_fonts(fonts::definitions(user));
+        status
     }
     
-    fn set_style (context: &egui::Context, mode: InterfaceMode) {
-        let mut vs: egui::Visuals;
-        match mode {
-            InterfaceMode::Dark  => {
-                context.set_theme(egui::Theme::Dark);
-                vs = egui::Visuals::dark();
-                vs.override_text_color = Option::Some(egui::Color32::from_gray(255));
-            },
-            InterfaceMode::Light => {
-                context.set_theme(egui::Theme::Light);
-                vs = egui::Visuals::light();
-                vs.override_text_color = Option::Some(egui::Color32::from_gray(0));
+    // Both palettes get the accent styling, so egui can swap between them live when following the system.
+    fn set_style (context: &egui::Context, mode: InterfaceMode, theme: &Theme) {
+        for (palette, mut vs, text) in [
+            (egui::Theme::Dark,  egui::Visuals::dark(),  theme.dark_text),
+            (egui::Theme::Light, egui::Visuals::light(), theme.light_text)
+        ] {
+            vs.override_text_color = Option::Some(text);
+            vs.widgets.active.bg_fill = theme.accent;
+            vs.widgets.noninteractive.bg_fill = theme.accent;
+            vs.selection.bg_fill = theme.accent.gamma_multiply(0.6);
+            vs.widgets.hovered.bg_fill = theme.accent;
+            vs.widgets.hovered.weak_bg_fill = theme.accent.gamma_multiply(0.1);
+            vs.slider_trailing_fill = true;
+            for ws in [&mut vs.widgets.noninteractive, &mut vs.widgets.inactive, &mut vs.widgets.hovered, &mut vs.widgets.active, &mut vs.widgets.open] {
+                ws.rounding = egui::Rounding::same(theme.rounding);
             }
+            context.set_visuals_of(palette, vs);
         }
-        vs.widgets.active.bg_fill = ACCENT_COLOR;
-        vs.widgets.noninteractive.bg_fill = ACCENT_COLOR;
-        vs.selection.bg_fill = ACCENT_COLOR.gamma_multiply(0.6);
-        vs.widgets.hovered.bg_fill = ACCENT_COLOR;
-        vs.widgets.hovered.weak_bg_fill = ACCENT_COLOR.gamma_multiply(0.1);
-        vs.slider_trailing_fill = true;
-        context.set_visuals(vs);
-    
+        context.set_theme(match mode {
+            InterfaceMode::Dark   => egui::ThemePreference::Dark,
+            InterfaceMode::Light  => egui::ThemePreference::Light,
+            InterfaceMode::System => egui::ThemePreference::System
+        });
     }
     
-    fn valid_start (&self) -> bool {
-        NaiveDate::parse_from_str(&self.start_date, DATEFORMAT).is_ok() && self.start_date.len() == 10 
+    fn show_history (&mut self, ui: &mut egui::Ui) {
+        ui.vertical(|ui| {
+            ui.label(egui::RichText::new(self.catalog.get("history")).small().weak());
+            ui.horizontal(|ui| {
+                if ui.add_enabled(self.history.past().next().is_some(), egui::Button::new(self.catalog.get("undo"))).clicked() {
+                    self.undo(1);
+                }
+                if ui.add_enabled(self.history.future().next().is_some(), egui::Button::new(self.catalog.get("redo"))).clicked() {
+                    self.redo(1);
+                }
+            });
+            ui.horizontal(|ui| {
+                ui.toggle_value(&mut self.show_history, self.catalog.get("list")).highlight();
+                ui.toggle_value(&mut self.show_shortcuts, self.catalog.get("keys")).highlight();
+            });
+            ui.horizontal(|ui| {
+                ui.toggle_value(&mut self.show_sensitivity, self.catalog.get("table")).highlight();
+                ui.toggle_value(&mut self.show_simulation, self.catalog.get("simulate")).highlight();
+                ui.toggle_value(&mut self.show_series, self.catalog.get("import")).highlight();
+            });
+        });
+    }
+
+    fn show_shortcuts_window (&mut self, context: &egui::Context) {
+        let shortcuts = [
+            (self.catalog.get("key-enter").to_string(),  "shortcut-recalculate"),
+            (context.format_shortcut(&SHORTCUT_UNDO),    "shortcut-undo"),
+            (context.format_shortcut(&SHORTCUT_REDO),    "shortcut-redo"),
+            (context.format_shortcut(&SHORTCUT_DARK),    "shortcut-dark-mode"),
+            (context.format_shortcut(&SHORTCUT_LARGER),  "shortcut-larger"),
+            (context.format_shortcut(&SHORTCUT_SMALLER), "shortcut-smaller"),
+            (context.format_shortcut(&SHORTCUT_RESET),   "shortcut-reset-zoom"),
+            (context.format_shortcut(&SHORTCUT_SAVE),    "shortcut-save"),
+            (context.format_shortcut(&SHORTCUT_HELP),    "shortcut-help"),
+            (self.catalog.get("key-tab").to_string(),    "shortcut-next-field"),
+            (self.catalog.get("key-space").to_string(),  "shortcut-toggle")
+        ];
+        let catalog = &self.catalog;
+        egui::Window::new(catalog.get("shortcuts-title")).id(egui::Id::new("shortcuts")).open(&mut self.show_shortcuts).resizable(false).collapsible(false).show(context, |ui| {
+            egui::Grid::new("shortcuts").num_columns(2).show(ui, |ui| {
+                for (keys, action) in shortcuts {
+                    ui.label(egui::RichText::new(keys).strong().family(fonts::bold()));
+                    ui.label(catalog.get(action));
+                    ui.end_row();
+                }
+            });
+        });
+    }
+
+    fn show_sensitivity_window (&mut self, context: &egui::Context) {
+        let mut open = self.show_sensitivity;
+        let start = number::parse_amount(&self.start_amount, self.number_locale).map(|(sv, _)| sv);
+        egui::Window::new(self.catalog.get("sensitivity-title")).id(egui::Id::new("sensitivity")).open(&mut open).collapsible(false).show(context, |ui| {
+            self.show_sensitivity_ranges(ui);
+            ui.separator();
+            match start {
+                Some(sv) => self.show_sensitivity_table(ui, sv),
+                None => {
+                    ui.label(egui::RichText::new(self.catalog.get("error-amount")).color(ui.visuals().error_fg_color));
+                }
+            }
+        });
+        self.show_sensitivity = open && self.show_sensitivity;
+    }
+
+    // The rates are set in percent, whatever unit they are shown in.
+    fn show_sensitivity_ranges (&mut self, ui: &mut egui::Ui) {
+        let (rates, years) = (&mut self.sensitivity.rates, &mut self.sensitivity.years);
+        let max_years = f64::from(MAX_YEARS);
+        egui::Grid::new("sensitivity-ranges").num_columns(4).show(ui, |ui| {
+            ui.label("");
+            ui.label(egui::RichText::new(self.catalog.get("sensitivity-from")).small().weak());
+            ui.label(egui::RichText::new(self.catalog.get("sensitivity-to")).small().weak());
+            ui.label(egui::RichText::new(self.catalog.get("sensitivity-step")).small().weak());
+            ui.end_row();
+            ui.label(self.catalog.get("sensitivity-rates"));
+            ui.add(egui::DragValue::new(&mut rates.from).range(-99.0..=100.0).speed(0.1).suffix(" %"));
+            ui.add(egui::DragValue::new(&mut rates.to).range(-99.0..=100.0).speed(0.1).suffix(" %"));
+            ui.add(egui::DragValue::new(&mut rates.step).range(0.1..=50.0).speed(0.1).suffix(" %"));
+            ui.end_row();
+            ui.label(self.catalog.get("sensitivity-years"));
+            ui.add(egui::DragValue::new(&mut years.from).range(0.0..=max_years).speed(0.5));
+            ui.add(egui::DragValue::new(&mut years.to).range(0.0..=max_years).speed(0.5));
+            ui.add(egui::DragValue::new(&mut years.step).range(0.5..=50.0).speed(0.5));
+            ui.end_row();
+        });
     }
 
-    fn valid_final (&self) -> bool {
-        NaiveDate::parse_from_str(&self.final_date, DATEFORMAT).is_ok() && self.final_date.len() == 10
+    // Final amounts colored from the lowest to the highest, with the color scale below.
+    fn show_sensitivity_table (&mut self, ui: &mut egui::Ui, sv: f64) {
+        let rates = self.sensitivity.rates.values();
+        let years = self.sensitivity.years.values();
+        let table = self.sensitivity.table(sv);
+        let (low, high) = table.iter().flatten().fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), fv| (lo.min(*fv), hi.max(*fv)));
+        let scale = sensitivity::scale();
+        let rate_label = match self.rate_unit {
+            RateUnit::Percent     => self.catalog.get("cagr"),
+            RateUnit::BasisPoints => self.catalog.get("cagr-bp")
+        };
+        egui::ScrollArea::both().max_height(360.0).show(ui, |ui| {
+            egui::Grid::new("sensitivity-table").spacing(egui::vec2(2.0, 2.0)).show(ui, |ui| {
+                ui.label(egui::RichText::new(format!("{rate_label} \\ {}", self.catalog.get("years"))).small().weak());
+                for yn in &years {
+                    ui.label(egui::RichText::new(number::format_amount(*yn, self.number_locale, "")).strong().family(fonts::bold()));
+                }
+                ui.end_row();
+                for (cc, row) in rates.iter().zip(&table) {
+                    ui.label(egui::RichText::new(number::format_rate(*cc, self.number_locale, self.rate_unit, self.rate_precision)).strong().family(fonts::bold()));
+                    for fv in row {
+                        let fill = scale.sample(sensitivity::heat(*fv, low, high));
+                        let text = number::format_amount(fv.round(), self.number_locale, &self.currency);
+                        egui::Frame::none().fill(fill).inner_margin(egui::Margin::symmetric(6.0, 2.0)).show(ui, |ui| {
+                            ui.label(egui::RichText::new(text).monospace().color(sensitivity::ink(fill)));
+                        });
+                    }
+                    ui.end_row();
+                }
+            });
+        });
+        ui.add_space(6.0);
+        ui.horizontal(|ui| {
+            ui.label(egui::RichText::new(number::format_amount(low.round(), self.number_locale, &self.currency)).small());
+            let (rect, _) = ui.allocate_exact_size(egui::vec2(120.0, 10.0), egui::Sense::hover());
+            let texture = self.textures.get(ui.ctx(), &scale);
+            gradient::paint(ui.painter(), rect, &scale, texture);
+            ui.label(egui::RichText::new(number::format_amount(high.round(), self.number_locale, &self.currency)).small());
+        });
     }
 
-    fn valid_range (&self) -> bool {
-        let sd = NaiveDate::parse_from_str(&self.start_date, DATEFORMAT);
-        let fd = NaiveDate::parse_from_str(&self.final_date, DATEFORMAT);
-        sd.is_ok() && fd.is_ok() && sd.unwrap_or_default() <= fd.unwrap_or_default()
+    fn show_simulation_window (&mut self, context: &egui::Context) {
+        let mut open = self.show_simulation;
+        egui::Window::new(self.catalog.get("simulation-title")).id(egui::Id::new("simulation")).open(&mut open).collapsible(false).show(context, |ui| {
+            self.show_simulation_model(ui);
+            ui.separator();
+            self.show_simulation_outcome(ui);
+        });
+        self.show_simulation = open && self.show_simulation;
+    }
+
+    // Rates are set in percent, whatever unit they are shown in. Without a target the final amount is used.
+    fn show_simulation_model (&mut self, ui: &mut egui::Ui) {
+        let (locale, currency) = (self.number_locale, self.currency.clone());
+        let cagr = self.eval_rate(&self.cagr).ok();
+        let model = &mut self.simulation.model;
+        egui::Grid::new("simulation-model").num_columns(2).show(ui, |ui| {
+            ui.label(self.catalog.get("simulation-expected"));
+            ui.horizontal(|ui| {
+                ui.add(egui::DragValue::new(&mut model.expected).range(-50.0..=50.0).speed(0.1).suffix(" %"));
+                if let Some(cc) = cagr.filter(|_| ui.button(self.catalog.get("simulation-from-cagr")).clicked()) {
+                    model.expected = cc.clamp(-50.0, 50.0);
+                }
+            });
+            ui.end_row();
+            ui.label(self.catalog.get("simulation-volatility"));
+            ui.add(egui::DragValue::new(&mut model.volatility).range(0.0..=100.0).speed(0.1).suffix(" %"));
+            ui.end_row();
+            ui.label(self.catalog.get("simulation-fat-tails"));
+            ui.add(Switch::bind(&mut model.fat_tails));
+            ui.end_row();
+            ui.label(self.catalog.get("simulation-runs"));
+            ui.add(egui::DragValue::new(&mut model.runs).range(100..=10_000).speed(10.0));
+            ui.end_row();
+            ui.label(self.catalog.get("simulation-seed"));
+            ui.add(egui::DragValue::new(&mut model.seed));
+            ui.end_row();
+            ui.label(self.catalog.get("simulation-target"));
+            ui.add(amount_field(&mut self.simulation.target, locale, &currency, &self.catalog).label(self.catalog.get("simulation-target")).hint(&self.final_amount));
+            ui.end_row();
+        });
+    }
+
+    fn show_simulation_outcome (&mut self, ui: &mut egui::Ui) {
+        let (locale, currency) = (self.number_locale, self.currency.clone());
+        let sd = self.parse_date(&self.start_date);
+        let fd = self.parse_date(&self.final_date);
+        let (Ok(sd), Ok(fd)) = (sd, fd) else {
+            ui.label(egui::RichText::new(self.explain(InputError::Date)).color(ui.visuals().error_fg_color));
+            return;
+        };
+        let Some((sv, _)) = number::parse_amount(&self.start_amount, locale).filter(|_| fd > sd) else {
+            let error = if fd > sd { InputError::Amount } else { InputError::FinalBeforeStart };
+            ui.label(egui::RichText::new(self.explain(error)).color(ui.visuals().error_fg_color));
+            return;
+        };
+        let years = f64::from(i32::try_from((fd - sd).num_days()).unwrap_or(0)) / 365.25;
+        let target = if self.simulation.target.trim().is_empty() { &self.final_amount } else { &self.simulation.target };
+        let target = number::parse_amount(target, locale).map(|(tv, _)| tv);
+        let amount = |fv: f64| number::format_amount(fv.round(), locale, &currency);
+        let (outcome, busy) = self.simulation.outcome(ui.ctx(), sv, years);
+        let Some(outcome) = outcome else {
+            ui.spinner();
+            return;
+        };
+        ui.add_enabled_ui(!busy, |ui| {
+            simulation::chart(ui, outcome, target, self.theme.accent, self.catalog.get("years"), amount);
+        });
+        ui.add_space(6.0);
+        egui::Grid::new("simulation-percentiles").num_columns(2).show(ui, |ui| {
+            let finals = outcome.points.last().map(|(_, ps)| *ps).unwrap_or_default();
+            for (p, fv) in simulation::PERCENTILES.iter().zip(finals).rev() {
+                ui.label(self.catalog.format("simulation-percentile", &[("p", &p.to_string())]));
+                ui.label(egui::RichText::new(amount(fv)).monospace());
+                ui.end_row();
+            }
+        });
+        if let Some((target, chance)) = target.map(|tv| (tv, 100.0 * outcome.probability(tv))) {
+            let chance = number::format_rate(chance, locale, RateUnit::Percent, RatePrecision::Decimals(1)); // A probability, not a rate.
+            ui.label(egui::RichText::new(self.catalog.format("simulation-probability", &[("target", &amount(target)), ("chance", &chance)])).strong().family(fonts::bold()));
+        }
+    }
+
+    fn show_series_window (&mut self, context: &egui::Context) {
+        let mut open = self.show_series;
+        egui::Window::new(self.catalog.get("series-title")).id(egui::Id::new("series")).open(&mut open).collapsible(false).show(context, |ui| {
+            ui.horizontal(|ui| {
+                ui.label(self.catalog.get("series-file"));
+                ui.text_edit_singleline(&mut self.series.path);
+                if ui.button(self.catalog.get("series-load")).clicked() {
+                    self.series_status = match self.series.load() {
+                        Ok(()) => String::new(),
+                        Err(e) => self.explain_read(e)
+                    };
+                }
+            });
+            if !self.series_status.is_empty() {
+                ui.colored_label(ui.visuals().error_fg_color, &self.series_status);
+            } else if self.series.skipped > 0 {
+                ui.label(egui::RichText::new(self.catalog.format("series-skipped", &[("rows", &self.series.skipped.to_string())])).weak());
+            }
+            if  self.series.points.len() >= 2 {
+                ui.separator();
+                self.show_series_selection(ui);
+                ui.separator();
+                self.show_series_metrics(ui);
+                ui.separator();
+                self.show_series_rolling(ui);
+            }
+        });
+        self.show_series = open && self.show_series;
+    }
+
+    // The sliders pick points by index, show their dates and take typed dates too.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_precision_loss)]
+    fn show_series_selection (&mut self, ui: &mut egui::Ui) {
+        let format = self.language.date_format();
+        let points = &self.series.points;
+        let last = points.len() - 1;
+        let show = |at: f64, _| points.get(at as usize).map(|(day, _)| day.format(format).to_string()).unwrap_or_default();
+        let find = |text: &str| NaiveDate::parse_from_str(text.trim(), format).ok().map(|d| points.partition_point(|(day, _)| *day < d) as f64);
+        egui::Grid::new("series-selection").num_columns(3).show(ui, |ui| {
+            for (key, at) in [("series-from", &mut self.series.from), ("series-to", &mut self.series.to)] {
+                ui.label(self.catalog.get(key));
+                ui.add(egui::Slider::new(at, 0..=last).custom_formatter(show).custom_parser(find));
+                let price = points.get(*at).map_or(0.0, |(_, price)| *price);
+                ui.label(egui::RichText::new(number::format_amount(price, self.number_locale, &self.currency)).monospace());
+                ui.end_row();
+            }
+        });
+        self.series.from = self.series.from.min(last - 1);
+        self.series.to = self.series.to.clamp(self.series.from + 1, last);
+        if ui.button(self.catalog.get("series-use")).clicked() {
+            let ((sd, sv), (fd, fv)) = (points[self.series.from], points[self.series.to]);
+            self.start_date = sd.format(format).to_string();
+            self.final_date = fd.format(format).to_string();
+            self.start_amount = number::format_amount(sv, self.number_locale, &self.currency);
+            self.final_amount = number::format_amount(fv, self.number_locale, &self.currency);
+            self.edited = Some("edit-import");
+            self.redo_parts();
+        }
+    }
+
+    fn show_series_metrics (&mut self, ui: &mut egui::Ui) {
+        let (locale, format) = (self.number_locale, self.language.date_format());
+        ui.horizontal(|ui| {
+            ui.label(self.catalog.get("series-risk-free"));
+            ui.add(egui::DragValue::new(&mut self.series.risk_free).range(-5.0..=20.0).speed(0.05).suffix(" %"));
+        });
+        let Some(metrics) = growth::metrics(self.series.selected(), self.series.risk_free) else {
+            return;
+        };
+        let rate = |cc: f64| self.rate_text(cc);
+        let ratio = |rv: Option<f64>| rv.map_or_else(|| String::from("–"), |rv| number::format_rate(rv, locale, RateUnit::Percent, RatePrecision::Decimals(2)));
+        let period = |(cc, sd, fd): growth::Period| format!("{}  {} – {}", rate(cc), sd.format(format), fd.format(format));
+        let rows = [
+            ("series-cagr",       rate(metrics.cagr)),
+            ("series-volatility", rate(metrics.volatility)),
+            ("series-drawdown",   period(metrics.drawdown)),
+            ("series-sharpe",     ratio(metrics.sharpe)),
+            ("series-sortino",    ratio(metrics.sortino))
+        ];
+        egui::Grid::new("series-metrics").num_columns(2).show(ui, |ui| {
+            for (key, value) in rows {
+                ui.label(self.catalog.get(key));
+                ui.label(egui::RichText::new(value).monospace());
+                ui.end_row();
+            }
+        });
+    }
+
+    // Every window of the rolling length within the selection, spread out and over time.
+    fn show_series_rolling (&mut self, ui: &mut egui::Ui) {
+        let format = self.language.date_format();
+        ui.horizontal(|ui| {
+            ui.label(self.catalog.get("series-window"));
+            ui.add(egui::DragValue::new(&mut self.series.window).range(0.1..=50.0).speed(0.1));
+        });
+        let periods = growth::rolling(self.series.selected(), self.series.window);
+        let rate = |cc: f64| self.rate_text(cc);
+        let period = |(cc, sd, fd): growth::Period| format!("{}  {} – {}", rate(cc), sd.format(format), fd.format(format));
+        let worst = periods.iter().copied().min_by(|a, b| a.0.total_cmp(&b.0));
+        let best  = periods.iter().copied().max_by(|a, b| a.0.total_cmp(&b.0));
+        let (Some(worst), Some(best), Some(median)) = (worst, best, growth::median(&periods)) else {
+            ui.label(egui::RichText::new(self.catalog.get("series-rolling-none")).weak());
+            return;
+        };
+        let rows = [
+            ("series-windows", periods.len().to_string()),
+            ("series-worst",   period(worst)),
+            ("series-median",  rate(median)),
+            ("series-best",    period(best))
+        ];
+        egui::Grid::new("series-rolling").num_columns(2).show(ui, |ui| {
+            for (key, value) in rows {
+                ui.label(self.catalog.get(key));
+                ui.label(egui::RichText::new(value).monospace());
+                ui.end_row();
+            }
+        });
+        ui.add_space(6.0);
+        series::histogram(ui, &periods, self.theme.accent, rate);
+        ui.add_space(6.0);
+        series::rolling_chart(ui, &periods, self.theme.accent, format, rate);
+    }
+
+    fn show_history_list (&mut self, ui: &mut egui::Ui) {
+        let past: Vec<String> = self.history.past().map(|label| self.catalog.get(label).to_string()).collect();
+        let future: Vec<String> = self.history.future().rev().map(|label| self.catalog.get(label).to_string()).collect();
+        for (i, label) in past.iter().enumerate() {
+            if ui.selectable_label(false, label).clicked() {
+                self.undo(past.len() - i);
+            }
+        }
+        ui.label(egui::RichText::new(self.catalog.get("history-current")).strong().family(fonts::bold()));
+        for (i, label) in future.iter().enumerate() {
+            if ui.selectable_label(false, egui::RichText::new(label).weak()).clicked() {
+                self.redo(i + 1);
+            }
+        }
+    }
+
+    fn show_history_window (&mut self, context: &egui::Context) {
+        let mut open = self.show_history;
+        egui::Window::new(self.catalog.get("history-title")).id(egui::Id::new("history")).open(&mut open).resizable(false).collapsible(false).show(context, |ui| {
+            egui::ScrollArea::vertical().max_height(200.0).show(ui, |ui| self.show_history_list(ui));
+        });
+        self.show_history = open && self.show_history;
+    }
+
+    fn show_history_panel (&mut self, context: &egui::Context) {
+        egui::SidePanel::right("history").frame(self.get_frame(context)).resizable(false).show(context, |ui| {
+            self.paint_backdrop(ui);
+            ui.label(egui::RichText::new(self.catalog.get("history")).small().weak());
+            egui::ScrollArea::vertical().show(ui, |ui| self.show_history_list(ui));
+        });
+    }
+
+    fn show_calculator (&mut self, ui: &mut egui::Ui, narrow: bool) {
+        let (start_error, final_error) = self.date_errors();
+        let start_error = start_error.map(|e| self.explain(e));
+        let final_error = final_error.map(|e| self.explain(e));
+        let start_amount_error = self.eval_amount(&self.start_amount).err().map(|e| self.explain_expression(&e, InputError::Amount));
+        let final_amount_error = self.eval_amount(&self.final_amount).err().map(|e| self.explain_expression(&e, InputError::Amount));
+        let cagr_error = match self.eval_rate(&self.cagr) {
+            Err(e) => Some(self.explain_expression(&e, InputError::Rate)),
+            Ok(cc) if !RATE_RANGE.contains(&cc) => Some(self.explain(InputError::RateRange)),
+            Ok(_) => None
+        };
+        let (locale, currency) = (self.number_locale, self.currency.clone());
+        let date_hint = self.example_date();
+        let amount_hint = number::format_amount(10_000.0, self.number_locale, &self.currency);
+        let rate_hint = number::format_rate(7.0, self.number_locale, self.rate_unit, self.rate_precision);
+        reflow(ui, narrow, |ui| {
+            ui.vertical(|ui| {
+                ui.label(egui::RichText::new(self.catalog.get("start-date")).small().weak());
+                if committed(&ui.add(ErrorField::new(&mut self.start_date).label(self.catalog.get("start-date")).error(start_error).hint(&date_hint).mask(self.language.date_mask()).inline(narrow).numeric())) {
+                    self.edited = Some("edit-start-date");
+                    self.redo_parts();
+                };
+                ui.add_space(12.0);
+                ui.label(egui::RichText::new(self.catalog.get("final-date")).small().weak());
+                if committed(&ui.add(ErrorField::new(&mut self.final_date).label(self.catalog.get("final-date")).error(final_error).hint(&date_hint).mask(self.language.date_mask()).inline(narrow).numeric())) {
+                    self.edited = Some("edit-final-date");
+                    self.redo_parts();
+                };
+            });
+            ui.add_space(36.0);
+            ui.vertical(|ui| {
+                ui.add_space(12.0);
+                // The years are logarithmic so single years stay easy to pick. Values beyond
+                // the other sliders can be typed, and are carried over to the larger units.
+                if ui.add(egui::Slider::new(&mut self.years,  0..=MAX_YEARS).logarithmic(true).smallest_positive(1.0).clamping(egui::SliderClamping::Edits).text(self.catalog.get("years"))).changed() {
+                    self.edited = Some("edit-years");
+                    self.redo_final();
+                };
+                if ui.add(egui::Slider::new(&mut self.months, 0..=11).clamping(egui::SliderClamping::Never).text(self.catalog.get("months"))).changed() {
+                    self.edited = Some("edit-months");
+                    self.redo_final();
+                    self.normalize();
+                };
+                if ui.add(egui::Slider::new(&mut self.weeks,  0..=4).clamping(egui::SliderClamping::Never).text(self.catalog.get("weeks"))).changed() {
+                    self.edited = Some("edit-weeks");
+                    self.redo_final();
+                    self.normalize();
+                };
+                if ui.add(egui::Slider::new(&mut self.days,   0..=6).clamping(egui::SliderClamping::Never).text(self.catalog.get("days"))).changed() {
+                    self.edited = Some("edit-days");
+                    self.redo_final();
+                    self.normalize();
+                };
+            });
+        });
+        ui.add_space(12.0);
+        ui.separator();
+        ui.add_space(12.0);
+        reflow(ui, narrow, |ui| {
+            ui.vertical(|ui| {
+                ui.label(egui::RichText::new(self.catalog.get("start-amount")).small().weak());
+                let response = ui.add(amount_field(&mut self.start_amount, locale, &currency, &self.catalog).label(self.catalog.get("start-amount")).error(start_amount_error).hint(&amount_hint).inline(narrow));
+                if committed(&response) {
+                    self.edited = Some("edit-start-amount");
+                    self.redo_amounts();
+                } else if response.changed() {
+                    self.edited = Some("edit-start-amount");
+                    self.redo_cagr();
+                }
+                ui.add_space(12.0);
+                ui.horizontal(|ui| {
+                    ui.vertical(|ui| {
+                        ui.label(egui::RichText::new(self.catalog.get("final-amount")).small().weak());
+                        let response = ui.add(amount_field(&mut self.final_amount, locale, &currency, &self.catalog).label(self.catalog.get("final-amount")).error(final_amount_error).hint(&amount_hint).inline(narrow));
+                        if committed(&response) {
+                            self.edited = Some("edit-final-amount");
+                            self.redo_amounts();
+                        } else if response.changed() {
+                            self.edited = Some("edit-final-amount");
+                            self.redo_cagr();
+                        };
+                    });
+                    ui.label(egui::RichText::new("\n  =  ").strong().family(fonts::bold()));
+                    ui.vertical(|ui| {
+                        let label = match self.rate_unit {
+                            RateUnit::Percent     => self.catalog.get("cagr"),
+                            RateUnit::BasisPoints => self.catalog.get("cagr-bp")
+                        };
+                        ui.label(egui::RichText::new(label).small().weak());
+                        let response = ui.add(rate_field(&mut self.cagr, locale, self.rate_unit, self.rate_precision, &self.catalog).label(label).error(cagr_error).hint(&rate_hint).inline(narrow));
+                        if committed(&response) || response.changed() {
+                            self.edited = Some("edit-cagr");
+                            self.redo_amount();
+                        };
+                    });
+                });
+            });
+            ui.add_space(12.0);
+            self.show_history(ui);
+        });
+    }
+
+    fn show_settings (&mut self, ui: &mut egui::Ui, narrow: bool) {
+        reflow(ui, narrow, |ui| {
+            ui.vertical(|ui| {
+                ui.label(egui::RichText::new(self.catalog.get("dark-mode")).small().weak());
+                let mut dark = match self.ui_mode {
+                    InterfaceMode::Dark   => Some(true),
+                    InterfaceMode::Light  => Some(false),
+                    InterfaceMode::System => None // The knob rests in the middle while following the system.
+                };
+                if ui.add(Switch::tri(&mut dark).texts("☾", "☀").label(self.catalog.get("dark-mode-switch"))).clicked() {
+                    self.flip_mode(ui.ctx());
+                };
+                if ui.selectable_label(self.ui_mode == InterfaceMode::System, self.catalog.get("mode-system")).highlight().clicked() {
+                    let mode = match (self.ui_mode, ui.ctx().theme()) {
+                        (InterfaceMode::System, egui::Theme::Dark)  => InterfaceMode::Dark, // Keep what is shown.
+                        (InterfaceMode::System, egui::Theme::Light) => InterfaceMode::Light,
+                        _ => InterfaceMode::System
+                    };
+                    self.remode(ui.ctx(), mode);
+                }
+            });
+            ui.add_space(12.0);
+            ui.vertical(|ui| {
+                ui.label(egui::RichText::new(self.catalog.get("scale")).small().weak());
+                ui.spacing_mut().slider_width = 60.0;
+                let mut zf = self.ui_size.factor();
+                let zoom = ui.add(egui::Slider::new(&mut zf, ZOOM_RANGE).step_by(0.05).fixed_decimals(2).text(self.catalog.get("scale-zoom")));
+                if  zoom.drag_stopped() || (zoom.changed() && !zoom.dragged()) { // Zooming while dragging moves the slider under the pointer.
+                    self.resize(ui.ctx(), zf);
+                }
+                let mut scale = self.text_scale;
+                ui.add(egui::Slider::new(&mut scale, TEXT_RANGE).step_by(0.05).fixed_decimals(2).text(self.catalog.get("scale-text")));
+                self.retext(ui.ctx(), scale);
+            });
+            ui.add_space(12.0);
+            ui.vertical(|ui| {
+                ui.label(egui::RichText::new(self.catalog.get("language")).small().weak());
+                let mut language = self.language;
+                egui::ComboBox::from_id_salt("language").width(80.0).selected_text(language.name()).show_ui(ui, |ui| {
+                    for option in Language::ALL {
+                        ui.selectable_value(&mut language, option, option.name());
+                    }
+                });
+                self.relanguage(language);
+            });
+        });
+        ui.add_space(12.0);
+        reflow(ui, narrow, |ui| {
+            ui.vertical(|ui| {
+                ui.label(egui::RichText::new(self.catalog.get("number-format")).small().weak());
+                ui.horizontal(|ui| {
+                    if ui.selectable_label(self.number_locale == NumberLocale::English, "1,000.00").highlight().clicked() {
+                        self.relocale(NumberLocale::English);
+                    }
+                    if ui.selectable_label(self.number_locale == NumberLocale::Swedish, "1 000,00").highlight().clicked() {
+                        self.relocale(NumberLocale::Swedish);
+                    }
+                    if ui.selectable_label(self.number_locale == NumberLocale::German,  "1.000,00").highlight().clicked() {
+                        self.relocale(NumberLocale::German);
+                    }
+                });
+            });
+            ui.add_space(12.0);
+            ui.vertical(|ui| {
+                ui.label(egui::RichText::new(self.catalog.get("currency")).small().weak());
+                let field = ErrorField::new(&mut self.currency).label(self.catalog.get("currency")).width(40.0).validate(|text| number::currency_code(text).map(|_| ()).ok_or(self.catalog.get("error-currency")));
+                if ui.add(field).lost_focus() && number::currency_code(&self.currency).is_some() {
+                    self.currency = self.currency.trim().to_string();
+                    self.edited = Some("edit-currency");
+                    self.reformat(self.number_locale);
+                }
+            });
+            ui.add_space(12.0);
+            ui.vertical(|ui| {
+                ui.label(egui::RichText::new(self.catalog.get("theme")).small().weak());
+                ui.toggle_value(&mut self.show_theme, self.catalog.get("theme-edit")).highlight();
+            });
+        });
+        ui.add_space(12.0);
+        self.show_rate_settings(ui, narrow);
+    }
+
+    // Rows of the theme grid.
+    fn show_gradient_rows (&self, ui: &mut egui::Ui, theme: &mut Theme) {
+        ui.horizontal(|ui| {
+            let (top, bottom) = match ui.ctx().theme() {
+                egui::Theme::Dark  => (theme.dark_background,  theme.dark_gradient),
+                egui::Theme::Light => (theme.light_background, theme.light_gradient)
+            };
+            let height = 0.8 * ui.spacing().interact_size.y;
+            ui.add(Switch::bind(&mut theme.gradient).size(height).colors(bottom, top).label(self.catalog.get("theme-gradient")));
+            ui.label(self.catalog.get("theme-gradient"));
+        });
+        ui.add_enabled_ui(theme.gradient, |ui| {
+            ui.horizontal(|ui| {
+                ui.color_edit_button_srgba(&mut theme.dark_gradient);
+                ui.color_edit_button_srgba(&mut theme.light_gradient);
+            });
+        });
+        ui.end_row();
+        ui.label(self.catalog.get("theme-shape"));
+        ui.add_enabled_ui(theme.gradient, |ui| {
+            ui.horizontal(|ui| {
+                let radial = theme.gradient_shape == Shape::Radial;
+                if ui.selectable_label(!radial, self.catalog.get("shape-linear")).clicked() && radial {
+                    theme.gradient_shape = Shape::Linear(180.0);
+                }
+                if ui.selectable_label(radial, self.catalog.get("shape-radial")).clicked() {
+                    theme.gradient_shape = Shape::Radial;
+                }
+                if let Shape::Linear(angle) = &mut theme.gradient_shape {
+                    ui.add(egui::DragValue::new(angle).range(0.0..=360.0).suffix("°"));
+                }
+            });
+        });
+        ui.end_row();
+        ui.label(self.catalog.get("theme-interpolation"));
+        ui.add_enabled_ui(theme.gradient, |ui| {
+            ui.horizontal(|ui| {
+                for (option, key) in [(Interpolation::Gamma, "mix-gamma"), (Interpolation::Linear, "mix-linear"), (Interpolation::Oklab, "mix-oklab")] {
+                    ui.selectable_value(&mut theme.gradient_interpolation, option, self.catalog.get(key));
+                }
+            });
+        });
+        ui.end_row();
+    }
+
+    fn show_theme_window (&mut self, context: &egui::Context) {
+        let mut theme = self.theme.clone();
+        let mut open = self.show_theme;
+        egui::Window::new(self.catalog.get("theme-title")).id(egui::Id::new("theme")).open(&mut open).resizable(false).collapsible(false).show(context, |ui| {
+            egui::Grid::new("theme").num_columns(2).show(ui, |ui| {
+                ui.label(self.catalog.get("theme-accent"));
+                ui.color_edit_button_srgba(&mut theme.accent);
+                ui.end_row();
+                ui.label(self.catalog.get("theme-dark"));
+                ui.horizontal(|ui| {
+                    ui.color_edit_button_srgba(&mut theme.dark_background);
+                    ui.color_edit_button_srgba(&mut theme.dark_text);
+                });
+                ui.end_row();
+                ui.label(self.catalog.get("theme-light"));
+                ui.horizontal(|ui| {
+                    ui.color_edit_button_srgba(&mut theme.light_background);
+                    ui.color_edit_button_srgba(&mut theme.light_text);
+                });
+                ui.end_row();
+                self.show_gradient_rows(ui, &mut theme);
+                ui.label(self.catalog.get("theme-rounding"));
+                ui.add(egui::Slider::new(&mut theme.rounding, 0.0..=12.0));
+                ui.end_row();
+                ui.label(self.catalog.get("theme-spacing"));
+                ui.add(egui::Slider::new(&mut theme.spacing, 4.0..=32.0));
+                ui.end_row();
+                ui.label(self.catalog.get("theme-file"));
+                ui.text_edit_singleline(&mut self.theme_path);
+                ui.end_row();
+            });
+            ui.horizontal(|ui| {
+                if ui.button(self.catalog.get("theme-import")).clicked() {
+                    match Theme::import(&self.theme_path) {
+                        Ok(imported) => { theme = imported; self.theme_status.clear(); },
+                        Err(e) => self.theme_status = e
+                    }
+                }
+                if ui.button(self.catalog.get("theme-export")).clicked() {
+                    self.theme_status = theme.export(&self.theme_path).err().unwrap_or_default();
+                }
+                if ui.button(self.catalog.get("theme-reset")).clicked() {
+                    theme = Theme::default();
+                }
+            });
+            if !self.theme_status.is_empty() {
+                ui.colored_label(ui.visuals().error_fg_color, &self.theme_status);
+            }
+            let stats = self.textures.stats();
+            let cache = self.catalog.format("theme-cache", &[
+                ("textures", &stats.textures.to_string()),
+                ("kb",       &(stats.bytes / 1024).to_string()),
+                ("hits",     &stats.hits.to_string()),
+                ("misses",   &stats.misses.to_string())
+            ]);
+            ui.label(egui::RichText::new(cache).small().weak());
+            ui.separator();
+            ui.horizontal(|ui| {
+                ui.label(self.catalog.get("font-file"));
+                ui.text_edit_singleline(&mut self.font_path);
+            });
+            ui.horizontal(|ui| {
+                if ui.button(self.catalog.get("font-load")).clicked() {
+                    self.font_status = Self::set_fonts(context, &self.font_path);
+                }
+                if ui.button(self.catalog.get("font-default")).clicked() {
+                    self.font_path.clear();
+                    self.font_status = Self::set_fonts(context, &self.font_path);
+                }
+            });
+            if !self.font_status.is_empty() {
+                ui.colored_label(ui.visuals().error_fg_color, &self.font_status);
+            }
+        });
+        self.show_theme = open && self.show_theme;
+        self.retheme(context, theme); // Applied on every change, so the app itself is the preview.
+    }
+
+    fn show_rate_settings (&mut self, ui: &mut egui::Ui, narrow: bool) {
+        let (mut significant, mut digits) = match self.rate_precision {
+            RatePrecision::Decimals(n)    => (false, n),
+            RatePrecision::Significant(n) => (true,  n)
+        };
+        let mut unit = self.rate_unit;
+        reflow(ui, narrow, |ui| {
+            ui.vertical(|ui| {
+                ui.label(egui::RichText::new(self.catalog.get("rate-precision")).small().weak());
+                ui.horizontal(|ui| {
+                    ui.selectable_value(&mut significant, false, self.catalog.get("decimals")).highlight();
+                    ui.selectable_value(&mut significant, true,  self.catalog.get("significant")).highlight();
+                    ui.add(egui::DragValue::new(&mut digits).range(u8::from(significant)..=6));
+                });
+            });
+            ui.add_space(12.0);
+            ui.vertical(|ui| {
+                ui.label(egui::RichText::new(self.catalog.get("rate-unit")).small().weak());
+                ui.horizontal(|ui| {
+                    ui.selectable_value(&mut unit, RateUnit::Percent,     "%" ).highlight();
+                    ui.selectable_value(&mut unit, RateUnit::BasisPoints, "bp").highlight();
+                });
+            });
+        });
+        let precision = if significant { RatePrecision::Significant(digits.max(1)) } else { RatePrecision::Decimals(digits) };
+        self.rerate(unit, precision);
+    }
+
+    fn parse_date (&self, text: &str) -> chrono::ParseResult<NaiveDate> {
+        NaiveDate::parse_from_str(text, self.language.date_format())
+    }
+
+    fn example_date (&self) -> String {
+        NaiveDate::from_ymd_opt(2025, 12, 31).unwrap_or_default().format(self.language.date_format()).to_string()
+    }
+
+    // A date can be fine on its own and still be on the wrong side of the other one.
+    fn date_errors (&self) -> (Option<InputError>, Option<InputError>) {
+        let sd = self.parse_date(&self.start_date).ok().filter(|_| self.start_date.len() == 10);
+        let fd = self.parse_date(&self.final_date).ok().filter(|_| self.final_date.len() == 10);
+        match (sd, fd) {
+            (Some(sd), Some(fd)) if sd > fd => (Some(InputError::StartAfterFinal), Some(InputError::FinalBeforeStart)),
+            _ => (sd.is_none().then_some(InputError::Date), fd.is_none().then_some(InputError::Date))
+        }
+    }
+
+    fn explain_read (&self, error: ReadError) -> String {
+        match error {
+            ReadError::File(e)  => e,
+            ReadError::NoDates  => self.catalog.get("error-series-dates").to_string(),
+            ReadError::TooFew   => self.catalog.get("error-series-few").to_string()
+        }
+    }
+
+    fn explain (&self, error: InputError) -> String {
+        match error {
+            InputError::Date             => self.catalog.format("error-date", &[("example", &self.example_date())]),
+            InputError::StartAfterFinal  => self.catalog.get("error-start-after-final").to_string(),
+            InputError::FinalBeforeStart => self.catalog.get("error-final-before-start").to_string(),
+            InputError::Amount           => self.catalog.get("error-amount").to_string(),
+            InputError::Rate             => self.catalog.get("error-rate").to_string(),
+            InputError::RateRange        => {
+                let low  = number::format_rate(*RATE_RANGE.start(), self.number_locale, self.rate_unit, self.rate_precision);
+                let high = number::format_rate(*RATE_RANGE.end(),   self.number_locale, self.rate_unit, self.rate_precision);
+                self.catalog.format("error-rate-range", &[("low", &low), ("high", &high)])
+            }
+        }
+    }
+
+    // A calculation is explained by what went wrong in it, anything else that can't be read by what was expected.
+    fn explain_expression (&self, error: &expression::Error, expected: InputError) -> String {
+        match error {
+            expression::Error::Syntax(0)         => self.explain(expected),
+            expression::Error::Syntax(at)        => self.catalog.format("error-syntax", &[("position", &(at + 1).to_string())]),
+            expression::Error::UnknownName(name) => self.catalog.format("error-unknown-name", &[("name", name)]),
+            expression::Error::DivisionByZero    => self.catalog.get("error-division-by-zero").to_string(),
+            expression::Error::NotFinite         => self.catalog.get("error-too-large").to_string()
+        }
     }
 
     fn redo_parts (&mut self) {
-        let sd = NaiveDate::parse_from_str(&self.start_date, DATEFORMAT);
-        let fd = NaiveDate::parse_from_str(&self.final_date, DATEFORMAT);
+        let sd = self.parse_date(&self.start_date);
+        let fd = self.parse_date(&self.final_date);
         if  sd.is_err() || fd.is_err() {
             return;
         }
@@ -174,7 +1274,7 @@ impl Compounder
     }
 
     fn redo_final (&mut self) {
-        let sd = NaiveDate::parse_from_str(&self.start_date, DATEFORMAT);
+        let sd = self.parse_date(&self.start_date);
         if  sd.is_err() {
             return;
         }
@@ -182,14 +1282,77 @@ impl Compounder
             .and_then(|r| r.checked_add_months(chrono::Months::new(12 * u32::from(self.years) + u32::from(self.months)))
             .and_then(|r| r.checked_add_days(chrono::Days::new(7 * u64::from(self.weeks) + u64::from(self.days))))
         ).unwrap_or_default();
-        self.final_date = fd.format(DATEFORMAT).to_string();
+        self.final_date = fd.format(self.language.date_format()).to_string();
+        self.redo_cagr();
+    }
+
+    // Turns typed values beyond the sliders into larger units, so 14 months become 1 year and 2 months.
+    // Weeks don't add up to whole months, so then the parts are taken from the final date instead.
+    fn normalize (&mut self) {
+        if  self.weeks.saturating_add(self.days / 7) > 4 {
+            self.redo_parts();
+            return;
+        }
+        self.years  = self.years.saturating_add(self.months / 12);
+        self.months %= 12;
+        self.weeks  = self.weeks.saturating_add(self.days / 7);
+        self.days   %= 7;
+    }
+
+    // Names usable in expressions: A is the start amount, B the final amount and C the rate.
+    fn names (&self) -> Vec<(&'static str, f64)> {
+        let sv = number::parse_amount(&self.start_amount, self.number_locale).map(|(sv, _)| ("A", sv));
+        let fv = number::parse_amount(&self.final_amount, self.number_locale).map(|(fv, _)| ("B", fv));
+        let cc = number::parse_number(self.cagr.trim(), self.number_locale).map(|cc| ("C", cc));
+        [sv, fv, cc].into_iter().flatten().collect()
+    }
+
+    // A rate in the unit and precision of the settings, with the unit.
+    fn rate_text (&self, cc: f64) -> String {
+        let text = number::format_rate(cc, self.number_locale, self.rate_unit, self.rate_precision);
+        match self.rate_unit {
+            RateUnit::Percent     => format!("{text} %"),
+            RateUnit::BasisPoints => format!("{text} bp")
+        }
+    }
+
+    fn eval_amount (&self, text: &str) -> Result<(f64, Option<String>), expression::Error> {
+        number::parse_amount(text, self.number_locale).map_or_else(|| {
+            expression::evaluate(text, self.number_locale, &self.names()).map(|v| (v, None))
+        }, Ok)
+    }
+
+    fn eval_rate (&self, text: &str) -> Result<f64, expression::Error> {
+        if let Some(cc) = number::parse_rate(text, self.number_locale, self.rate_unit) {
+            return Ok(cc);
+        }
+        let cc = expression::evaluate(text, self.number_locale, &self.names())?;
+        match self.rate_unit {
+            RateUnit::Percent     => Ok(cc),
+            RateUnit::BasisPoints => Ok(cc / 100.0)
+        }
+    }
+
+    fn redo_amounts (&mut self) {
+        if let Ok((sv, cy)) = self.eval_amount(&self.start_amount) {
+            if let Some(cy) = cy {
+                self.currency = cy;
+            }
+            self.start_amount = number::format_amount(sv, self.number_locale, &self.currency);
+        }
+        if let Ok((fv, cy)) = self.eval_amount(&self.final_amount) {
+            if let Some(cy) = cy {
+                self.currency = cy;
+            }
+            self.final_amount = number::format_amount(fv, self.number_locale, &self.currency);
+        }
+        self.reformat(self.number_locale);
         self.redo_cagr();
     }
 
     fn redo_cagr (&mut self) {
-        //TODO: refactor string to dates into own metod?
-        let sd = NaiveDate::parse_from_str(&self.start_date, DATEFORMAT);
-        let fd = NaiveDate::parse_from_str(&self.final_date, DATEFORMAT);
+        let sd = self.parse_date(&self.start_date);
+        let fd = self.parse_date(&self.final_date);
         if  sd.is_err() || fd.is_err() {
             return;
         }
@@ -202,24 +1365,24 @@ impl Compounder
         if  nd == 0 {
             return;
         }
-        let sv = self.start_amount.trim().parse::<f64>();
-        let fv = self.final_amount.trim().parse::<f64>();
-        if sv.is_err() || fv.is_err() {
+        let sv = number::parse_amount(&self.start_amount, self.number_locale);
+        let fv = number::parse_amount(&self.final_amount, self.number_locale);
+        if sv.is_none() || fv.is_none() {
             return;
         }
-        let sv = sv.unwrap_or_default();
-        let fv = fv.unwrap_or_default();
-        let cc = ((fv/sv).powf(1.0 / (f64::from(i32::try_from(nd).unwrap_or(0)) / 365.25)) - 1.0) * 100.0;
-        let dp = match cc {
-            0.0..100.0 => 1,
-            _ => 0
-        };
-        self.cagr = format!("{cc:.dp$}");
+        let (sv, _) = sv.unwrap_or_default();
+        let (fv, _) = fv.unwrap_or_default();
+        let cc = growth::cagr(sv, fv, growth::years(sd, fd));
+        self.cagr = number::format_rate(cc, self.number_locale, self.rate_unit, self.rate_precision);
     }
 
     fn redo_amount (&mut self) {
-        let sd = NaiveDate::parse_from_str(&self.start_date, DATEFORMAT);
-        let fd = NaiveDate::parse_from_str(&self.final_date, DATEFORMAT);
+        let cc = self.eval_rate(&self.cagr).ok();
+        if let Some(cc) = cc.filter(|_| number::parse_rate(&self.cagr, self.number_locale, self.rate_unit).is_none()) {
+            self.cagr = number::format_rate(cc, self.number_locale, self.rate_unit, self.rate_precision);
+        }
+        let sd = self.parse_date(&self.start_date);
+        let fd = self.parse_date(&self.final_date);
         if  sd.is_err() || fd.is_err() {
             return;
         }
@@ -232,15 +1395,15 @@ impl Compounder
         if  nd == 0 {
             return;
         }
-        let sv = self.start_amount.trim().parse::<f64>();
-        let cc = self.cagr.trim().parse::<f64>();
-        if sv.is_err() || cc.is_err() {
+        let sv = number::parse_amount(&self.start_amount, self.number_locale);
+        let cc = cc.filter(|cc| RATE_RANGE.contains(cc));
+        if sv.is_none() || cc.is_none() {
             return;
         }
-        let sv = sv.unwrap_or_default();
+        let (sv, _) = sv.unwrap_or_default();
         let cc = cc.unwrap_or_default() / 100.0;
         let fv = sv * (1.0 + cc).powf(f64::from(i32::try_from(nd).unwrap_or(0)) / 365.25);
-        self.final_amount = fv.round().to_string();
+        self.final_amount = number::format_amount(fv.round(), self.number_locale, &self.currency);
     }
 
 }
@@ -250,17 +1413,42 @@ impl Default for Compounder
     fn default() -> Self {
         let dt = chrono::Local::now().date_naive();
         Self {
-            start_date: dt.to_string(),
-            final_date: dt.checked_add_months(chrono::Months::new(12)).unwrap_or_default().to_string(),
+            start_date: dt.format(Language::English.date_format()).to_string(),
+            final_date: dt.checked_add_months(chrono::Months::new(12)).unwrap_or_default().format(Language::English.date_format()).to_string(),
             years: 1,
             months: 0,
             weeks: 0,
             days: 0,
-            start_amount: String::from("1000"),
-            final_amount: String::from("1100"),
-            cagr: String::from("10"),
-            ui_size: InterfaceSize::Small,
-            ui_mode: InterfaceMode::Dark
+            start_amount: number::format_amount(1000.0, NumberLocale::English, ""),
+            final_amount: number::format_amount(1100.0, NumberLocale::English, ""),
+            cagr: number::format_rate(10.0, NumberLocale::English, RateUnit::Percent, RatePrecision::Decimals(1)),
+            ui_size: InterfaceSize::Zoom(1.0),
+            text_scale: 1.0,
+            ui_mode: InterfaceMode::Dark,
+            number_locale: NumberLocale::English,
+            currency: String::new(),
+            rate_precision: RatePrecision::Decimals(1),
+            rate_unit: RateUnit::Percent,
+            language: Language::English,
+            theme: Theme::default(),
+            theme_path: String::from("compounder-theme.ron"),
+            sensitivity: Sensitivity::default(),
+            simulation: Simulation::default(),
+            series: Series::default(),
+            series_status: String::new(),
+            theme_status: String::new(),
+            font_path: String::new(),
+            font_status: String::new(),
+            show_theme: false,
+            textures: TextureManager::default(),
+            catalog: Catalog::default(),
+            history: History::default(),
+            edited: None,
+            show_history: false,
+            show_shortcuts: false,
+            show_sensitivity: false,
+            show_simulation: false,
+            show_series: false
         }
     }
 }
@@ -271,105 +1459,85 @@ impl App for Compounder
         eframe::set_value(storage, eframe::APP_KEY, self);
     }
 
-    fn update (&mut self, context: &egui::Context, _frame: &mut Frame) {
-        let start_is_valid = self.valid_start();
-        let final_is_valid = self.valid_final();
-        let range_is_valid = self.valid_range();
-        egui::CentralPanel::default().frame(self.get_frame()).show(context, |ui| {
+    fn update (&mut self, context: &egui::Context, frame: &mut Frame) {
+        self.handle_shortcuts(context, frame);
+        let wide = context.screen_rect().width() >= WIDE_WIDTH;
+        if  wide && self.show_history {
+            self.show_history_panel(context);
+        }
+        egui::CentralPanel::default().frame(self.get_frame(context)).show(context, |ui| {
+            self.paint_backdrop(ui);
             let styles = ui.style_mut();
-            styles.spacing.item_spacing = egui::Vec2::new(16.0, 8.0);
+            styles.spacing.item_spacing = egui::Vec2::new(self.theme.spacing, 0.5 * self.theme.spacing);
             styles.spacing.text_edit_width = 75.0;
-            // egui::Image::new (egui::include_image!("../assets/Panel-Background.svg")).paint_at(ui, ui.ctx().screen_rect());
-            ui.horizontal(|ui| {
-                ui.vertical(|ui| {
-                    ui.label(egui::RichText::new("START DATE").small().weak());
-                    if ui.add(ErrorField::new(&mut self.start_date, start_is_valid && (!final_is_valid || range_is_valid))).lost_focus() {
-                        self.redo_parts();
-                    };
-                    ui.add_space(12.0);
-                    ui.label(egui::RichText::new("FINAL DATE").small().weak());
-                    if ui.add(ErrorField::new(&mut self.final_date, final_is_valid && (!start_is_valid || range_is_valid))).lost_focus() {
-                        self.redo_parts();
-                    };
-                });
-                ui.add_space(36.0);
-                ui.vertical(|ui| {
-                    ui.add_space(12.0);
-                    if ui.add(egui::Slider::new(&mut self.years,  0..=50).text("years")).changed() {
-                        self.redo_final();
-                    };
-                    if ui.add(egui::Slider::new(&mut self.months, 0..=11).text("months")).changed() {
-                        self.redo_final();
-                    };
-                    if ui.add(egui::Slider::new(&mut self.weeks,  0..=4).text("weeks")).changed() {
-                        self.redo_final();
-                    };
-                    if ui.add(egui::Slider::new(&mut self.days,   0..=6).text("days")).changed() {
-                        self.redo_final();
-                    };
-                });
-            });
-            ui.add_space(12.0);
-            ui.separator();
-            ui.add_space(12.0);
-            ui.horizontal(|ui| {
-                ui.vertical(|ui| {
-                    ui.label(egui::RichText::new("START AMOUNT").small().weak());
-                    if ui.text_edit_singleline(&mut self.start_amount).highlight().lost_focus() {
-                        self.redo_cagr();
-                    }
-                    ui.add_space(12.0);
-                    ui.horizontal(|ui| {
-                        ui.vertical(|ui| {
-                            ui.label(egui::RichText::new("FINAL AMOUNT").small().weak());
-                            if ui.text_edit_singleline(&mut self.final_amount).highlight().lost_focus() {
-                                self.redo_cagr();
-                            };
-                        });
-                        ui.label(egui::RichText::new("\n  =  ").strong());
-                        ui.vertical(|ui| {
-                            ui.label(egui::RichText::new("CAGR").small().weak());
-                            if ui.text_edit_singleline(&mut self.cagr).highlight().lost_focus() {
-                                self.redo_amount();
-                            };
-                        });
-                    });
-                });
-            });
-            ui.add_space(12.0);
-            ui.separator();
-            ui.add_space(12.0);
-            ui.horizontal(|ui| {
-                ui.vertical(|ui| {
-                    ui.label(egui::RichText::new("DARK MODE").small().weak());
-                    if ui.add(Switch::new(InterfaceMode::Dark == self.ui_mode)).clicked() {
-                        match self.ui_mode {
-                            InterfaceMode::Dark  => self.remode(ui.ctx(), InterfaceMode::Light),
-                            InterfaceMode::Light => self.remode(ui.ctx(), InterfaceMode::Dark)
-                        }
-                    };
-                });
+            let narrow = ui.available_width() < NARROW_WIDTH;
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                self.show_calculator(ui, narrow);
                 ui.add_space(12.0);
-                ui.vertical(|ui| {
-                    ui.label(egui::RichText::new("TEXT SIZE").small().weak());
-                    ui.horizontal(|ui| {
-                        if ui.selectable_label(self.ui_size == InterfaceSize::Small,  "small" ).highlight().clicked() {
-                            self.resize(ui.ctx(), InterfaceSize::Small);
-                        };
-                        if ui.selectable_label(self.ui_size == InterfaceSize::Medium, "medium").highlight().clicked() {
-                            self.resize(ui.ctx(), InterfaceSize::Medium);
-                        };
-                        if ui.selectable_label(self.ui_size == InterfaceSize::Large,  "large" ).highlight().clicked() {
-                            self.resize(ui.ctx(), InterfaceSize::Large);
-                        };
-                    });
-                });
+                ui.separator();
+                ui.add_space(12.0);
+                self.show_settings(ui, narrow);
             });
         });
+        if !wide {
+            self.show_history_window(context);
+        }
+        self.show_shortcuts_window(context);
+        self.show_sensitivity_window(context);
+        self.show_simulation_window(context);
+        self.show_series_window(context);
+        self.show_theme_window(context);
+        self.record(context);
     }
 }
 
-fn date_difference(sd: NaiveDate, fd: NaiveDate) -> (u8, u8, u8, u8) {
+// Lays out a row side by side, or stacked when the window is narrow.
+fn reflow<R> (ui: &mut egui::Ui, narrow: bool, add_contents: impl FnOnce(&mut egui::Ui) -> R) -> R {
+    if  narrow {
+        ui.vertical(add_contents).inner
+    } else {
+        ui.horizontal(add_contents).inner
+    }
+}
+
+// Fields commit when they lose focus. Enter commits too, but keeps the cursor in the field.
+fn committed (response: &egui::Response) -> bool {
+    if  response.lost_focus() && response.ctx.input(|i| i.key_pressed(egui::Key::Enter)) {
+        response.request_focus();
+    }
+    response.lost_focus()
+}
+
+// The names screen readers give the handle and buttons of number fields.
+fn controls (catalog: &Catalog) -> [&str; 3] {
+    [catalog.get("number-drag"), catalog.get("number-down"), catalog.get("number-up")]
+}
+
+// Amounts step by a hundredth of their order of magnitude, but at least by one.
+fn amount_field<'a> (text: &'a mut String, locale: NumberLocale, currency: &'a str, catalog: &'a Catalog) -> NumberField<'a> {
+    let value = number::parse_amount(text, locale).map_or(0.0, |(v, _)| v);
+    let step = 10_f64.powf(value.abs().log10().floor() - 2.0).max(1.0);
+    NumberField::new(text).range(AMOUNT_RANGE).step(step).controls(controls(catalog))
+        .parse(move |text| number::parse_amount(text, locale).map(|(v, _)| v))
+        .format(move |v| number::format_amount(v, locale, currency))
+}
+
+// Rates step by the last digit shown.
+fn rate_field<'a> (text: &'a mut String, locale: NumberLocale, unit: RateUnit, precision: RatePrecision, catalog: &'a Catalog) -> NumberField<'a> {
+    let shown = match precision {
+        RatePrecision::Decimals(n)    => 0.1_f64.powi(i32::from(n)),
+        RatePrecision::Significant(_) => 0.1
+    };
+    let step = match unit {
+        RateUnit::Percent     => shown,
+        RateUnit::BasisPoints => shown / 100.0
+    };
+    NumberField::new(text).range(RATE_RANGE).step(step).controls(controls(catalog))
+        .parse(move |text| number::parse_rate(text, locale, unit))
+        .format(move |cc| number::format_rate(cc, locale, unit, precision))
+}
+
+fn date_difference(sd: NaiveDate, fd: NaiveDate) -> (u16, u16, u16, u16) {
     // Solution suggested by ChatGPT (added number of weeks and adjusted remaining days accordingly).
     use chrono::Datelike;
     let mut yn = fd.year() - sd.year();
@@ -390,10 +1558,10 @@ fn date_difference(sd: NaiveDate, fd: NaiveDate) -> (u8, u8, u8, u8) {
         mn += 12;
     }
     (
-        u8::try_from(yn).unwrap_or(0), 
-        u8::try_from(mn).unwrap_or(0), 
-        u8::try_from(dn / 7).unwrap_or(0), 
-        u8::try_from(dn % 7).unwrap_or(0)
+        u16::try_from(yn).unwrap_or(0), 
+        u16::try_from(mn).unwrap_or(0), 
+        u16::try_from(dn / 7).unwrap_or(0), 
+        u16::try_from(dn % 7).unwrap_or(0)
     )
 }
 
@@ -424,10 +1592,10 @@ fn main() -> eframe::Result {
         "Compounder", 
         eframe::NativeOptions {
             viewport: eframe::egui::ViewportBuilder::default()
-                .with_resizable(false)
-                .with_maximize_button(false)
                 .with_inner_size(GUI_SIZE)
+                .with_min_inner_size(MIN_SIZE)
                 .with_icon(eframe::icon_data::from_png_bytes(&include_bytes!("../assets/Compounder.png")[..]).unwrap_or_default()),
+            persist_window: true,
             ..Default::default()
         },
         Box::new(|context| {
@@ -0,0 +1,269 @@
+#![deny(clippy::pedantic)]
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![deny(unused_must_use)]
+
+const SYMBOLS: [char; 4] = ['$', '€', '£', '¥'];
+
+#[derive(serde::Deserialize, serde::Serialize, PartialEq, Copy, Clone)]
+pub enum NumberLocale
+{
+    English, // 1,234,567.89
+    Swedish, // 1 234 567,89
+    German   // 1.234.567,89
+}
+
+#[derive(serde::Deserialize, serde::Serialize, PartialEq, Copy, Clone)]
+pub enum RatePrecision
+{
+    Decimals(u8),
+    Significant(u8)
+}
+
+#[derive(serde::Deserialize, serde::Serialize, PartialEq, Copy, Clone)]
+pub enum RateUnit
+{
+    Percent,
+    BasisPoints
+}
+
+impl NumberLocale
+{
+    pub const fn group (self) -> char {
+        match self {
+            NumberLocale::English => ',',
+            NumberLocale::Swedish => ' ',
+            NumberLocale::German  => '.'
+        }
+    }
+
+    pub const fn decimal (self) -> char {
+        match self {
+            NumberLocale::English => '.',
+            NumberLocale::Swedish | NumberLocale::German => ','
+        }
+    }
+}
+
+/// Parses an amount like `1,000,000`, `1 000 000`, `1.000.000,50`, `$1k` or `2.5M SEK`.
+/// Returns the value and the currency code or symbol, if one was given.
+pub fn parse_amount (text: &str, locale: NumberLocale) -> Option<(f64, Option<String>)> {
+    let mut text = text.trim();
+    let mut negative = false;
+    if let Some(t) = text.strip_prefix('-') {
+        negative = true;
+        text = t.trim_start();
+    }
+    let start = text.find(|c: char| c.is_ascii_digit() || c == '.' || c == ',' || c == '-')?;
+    let (prefix, rest) = text.split_at(start);
+    let rest = match rest.strip_prefix('-') {
+        Some(r) if !negative => { negative = true; r },
+        _ => rest
+    };
+    let end = rest.rfind(|c: char| c.is_ascii_digit() || c == '.' || c == ',').map_or(0, |i| i + 1);
+    let (core, suffix) = rest.split_at(end);
+    let suffix = suffix.trim_start();
+    let (multiplier, suffix) = match suffix.chars().next() {
+        Some(c) if suffix[c.len_utf8()..].is_empty() || suffix[c.len_utf8()..].starts_with(char::is_whitespace) => match c {
+            'k' | 'K' => (1e3, &suffix[1..]),
+            'm' | 'M' => (1e6, &suffix[1..]),
+            'b' | 'B' => (1e9, &suffix[1..]),
+            _ => (1.0, suffix)
+        },
+        _ => (1.0, suffix)
+    };
+    let currency = parse_currency(prefix.trim(), suffix.trim())?;
+    let value = parse_number(core, locale)? * multiplier;
+    let currency = Some(currency).filter(|c| !c.is_empty()).map(str::to_string);
+    Some((if negative { -value } else { value }, currency))
+}
+
+/// Formats an amount with the grouping and decimal separator of the locale,
+/// showing decimals only when the amount has cents.
+pub fn format_amount (value: f64, locale: NumberLocale, currency: &str) -> String {
+    let dp = if (value * 100.0).round() % 100.0 == 0.0 { 0 } else { 2 };
+    let text = format!("{:.dp$}", value.abs());
+    let (whole, fraction) = text.split_once('.').unwrap_or((&text, ""));
+    let mut number = String::new();
+    for (i, c) in whole.chars().enumerate() {
+        if i > 0 && (whole.len() - i) % 3 == 0 {
+            number.push(locale.group());
+        }
+        number.push(c);
+    }
+    if !fraction.is_empty() {
+        number.push(locale.decimal());
+        number.push_str(fraction);
+    }
+    let sign = if value < 0.0 && text.chars().any(|c| c.is_ascii_digit() && c != '0') { "-" } else { "" };
+    match currency.trim() {
+        "" => format!("{sign}{number}"),
+        c if c.chars().all(|c| SYMBOLS.contains(&c)) => format!("{sign}{c}{number}"),
+        c => format!("{sign}{number} {c}")
+    }
+}
+
+/// Parses a rate in percent, accepting an explicit `%` or `bp` suffix and otherwise
+/// reading the number in the given unit.
+pub fn parse_rate (text: &str, locale: NumberLocale, unit: RateUnit) -> Option<f64> {
+    let text = text.trim();
+    let (core, unit) = if let Some(t) = text.strip_suffix('%') {
+        (t, RateUnit::Percent)
+    } else if let Some(t) = text.strip_suffix("bps").or_else(|| text.strip_suffix("bp")) {
+        (t, RateUnit::BasisPoints)
+    } else {
+        (text, unit)
+    };
+    let core = core.trim();
+    let (core, negative) = core.strip_prefix('-').map_or((core, false), |c| (c, true));
+    let rate = parse_number(core, locale)?;
+    let rate = if negative { -rate } else { rate };
+    match unit {
+        RateUnit::Percent     => Some(rate),
+        RateUnit::BasisPoints => Some(rate / 100.0)
+    }
+}
+
+/// Formats a rate given in percent. Negative rates are rounded by magnitude,
+/// exactly like positive ones.
+pub fn format_rate (rate: f64, locale: NumberLocale, unit: RateUnit, precision: RatePrecision) -> String {
+    let value = match unit {
+        RateUnit::Percent     => rate,
+        RateUnit::BasisPoints => rate * 100.0
+    };
+    let (value, dp) = match precision {
+        RatePrecision::Decimals(n) => (value, usize::from(n)),
+        RatePrecision::Significant(n) => {
+            if value == 0.0 || !value.is_finite() {
+                (value, 0)
+            } else {
+                let sd = i32::from(n.max(1));
+                #[allow(clippy::cast_possible_truncation)]
+                let mg = value.abs().log10().floor() as i32;
+                let sc = 10_f64.powi(sd - 1 - mg);
+                ((value * sc).round() / sc, usize::try_from(sd - 1 - mg).unwrap_or(0))
+            }
+        }
+    };
+    let text = format!("{:.dp$}", value.abs());
+    let sign = if value < 0.0 && text.chars().any(|c| c.is_ascii_digit() && c != '0') { "-" } else { "" };
+    format!("{sign}{}", text.replace('.', &locale.decimal().to_string()))
+}
+
+/// Checks a currency on its own: empty, one of the symbols, or up to three letters.
+pub fn currency_code (text: &str) -> Option<&str> {
+    parse_currency(text.trim(), "")
+}
+
+fn parse_currency<'a> (prefix: &'a str, suffix: &'a str) -> Option<&'a str> {
+    let code = match (prefix.is_empty(), suffix.is_empty()) {
+        (false, false) => return None,
+        (false, true ) => prefix,
+        _ => suffix
+    };
+    let symbol = code.chars().count() == 1 && code.chars().all(|c| SYMBOLS.contains(&c));
+    let letters = code.chars().count() <= 3 && code.chars().all(char::is_alphabetic);
+    if symbol || letters { Some(code) } else { None }
+}
+
+/// Parses a plain number, telling decimal and grouping separators apart by position.
+/// A lone separator before three digits is only taken for grouping when it is the locale's
+/// and the integer part is short enough to be a group, so `1,234` is grouped but `1234,567` isn't.
+pub fn parse_number (core: &str, locale: NumberLocale) -> Option<f64> {
+    let core: String = core.chars().filter(|c| !c.is_whitespace() && *c != '\'').collect();
+    let dots   = core.matches('.').count();
+    let commas = core.matches(',').count();
+    let decimal = match (dots, commas) {
+        (0, 0) => None,
+        (_, 0) | (0, _) => {
+            let c = if dots > 0 { '.' } else { ',' };
+            let (whole, fraction) = core.rsplit_once(c).unwrap_or_default();
+            let group = c == locale.group() && fraction.len() == 3 && (1..=3).contains(&whole.len());
+            if  dots + commas == 1 && !group {
+                Some(c)
+            } else if grouped(&core, c) {
+                None
+            } else {
+                return None;
+            }
+        },
+        _ => {
+            let i = core.rfind(['.', ','])?;
+            let c = if core[i..].starts_with('.') { '.' } else { ',' };
+            if !grouped(&core[..i], if c == '.' { ',' } else { '.' }) {
+                return None;
+            }
+            Some(c)
+        }
+    };
+    let core: String = core.chars()
+        .filter(|c| Some(*c) == decimal || (*c != '.' && *c != ','))
+        .map(|c| if Some(c) == decimal { '.' } else { c })
+        .collect();
+    if core.matches('.').count() > 1 {
+        return None;
+    }
+    core.parse::<f64>().ok().filter(|v| v.is_finite())
+}
+
+// Whether an integer part is grouped by thousands, like `1,234,567`.
+fn grouped (whole: &str, separator: char) -> bool {
+    let mut groups = whole.split(separator);
+    groups.next().is_some_and(|g| (1..=3).contains(&g.len())) && groups.all(|g| g.len() == 3)
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn lone_separators () {
+        assert_eq!(parse_number("1,234", NumberLocale::English), Some(1234.0));
+        assert_eq!(parse_number("1,5", NumberLocale::English), Some(1.5));
+        assert_eq!(parse_number("1234,567", NumberLocale::English), Some(1234.567));
+        assert_eq!(parse_number("1.234", NumberLocale::German), Some(1234.0));
+        assert_eq!(parse_number("1234.567", NumberLocale::German), Some(1234.567));
+        assert_eq!(parse_number("100.125", NumberLocale::English), Some(100.125));
+        assert_eq!(parse_number("1,234", NumberLocale::Swedish), Some(1.234));
+    }
+
+    #[test]
+    fn grouping () {
+        assert_eq!(parse_number("1,234,567.89", NumberLocale::English), Some(1_234_567.89));
+        assert_eq!(parse_number("1.234.567,89", NumberLocale::German), Some(1_234_567.89));
+        assert_eq!(parse_number("1 234 567,89", NumberLocale::Swedish), Some(1_234_567.89));
+        assert_eq!(parse_number("1.234.567", NumberLocale::English), Some(1_234_567.0));
+        assert_eq!(parse_number("12,34,567", NumberLocale::English), None);
+        assert_eq!(parse_number("1,2345.6", NumberLocale::English), None);
+        assert_eq!(parse_number("1.2.3", NumberLocale::German), None);
+    }
+
+    #[test]
+    fn amounts () {
+        assert_eq!(parse_amount("$1,234", NumberLocale::English), Some((1234.0, Some(String::from("$")))));
+        assert_eq!(parse_amount("2.5M SEK", NumberLocale::English), Some((2.5e6, Some(String::from("SEK")))));
+        assert_eq!(parse_amount("-1.234,50 €", NumberLocale::German), Some((-1234.5, Some(String::from("€")))));
+        assert_eq!(parse_amount("1234.567", NumberLocale::German), Some((1234.567, None)));
+        assert_eq!(parse_amount("1 500", NumberLocale::Swedish), Some((1500.0, None)));
+        assert_eq!(parse_amount("1 500 $ SEK", NumberLocale::Swedish), None);
+    }
+
+    #[test]
+    fn formatting () {
+        assert_eq!(format_amount(1_234_567.5, NumberLocale::English, ""), "1,234,567.50");
+        assert_eq!(format_amount(1500.0, NumberLocale::Swedish, "SEK"), "1 500 SEK");
+        assert_eq!(format_amount(-1234.0, NumberLocale::German, "€"), "-€1.234");
+        assert_eq!(format_amount(-0.001, NumberLocale::English, ""), "0");
+    }
+
+    #[test]
+    fn round_trip () {
+        for locale in [NumberLocale::English, NumberLocale::Swedish, NumberLocale::German] {
+            for value in [0.5, 100.25, 1234.0, 123_456.78, 1_000_000.0] {
+                assert_eq!(parse_amount(&format_amount(value, locale, ""), locale), Some((value, None)));
+            }
+        }
+    }
+}
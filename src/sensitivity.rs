@@ -0,0 +1,123 @@
+#![deny(clippy::pedantic)]
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![deny(unused_must_use)]
+
+use eframe::egui::Color32;
+
+use compounder_widgets::gradient::{
+    Gradient,
+    Interpolation,
+    Shape
+};
+
+const MAX_STEPS: usize = 25; // Per axis, more wouldn't fit in a window anyway.
+
+// One axis of the table, with both ends included.
+#[derive(serde::Deserialize, serde::Serialize, PartialEq, Copy, Clone)]
+pub struct Axis
+{
+    pub from: f64,
+    pub to: f64,
+    pub step: f64
+}
+
+// Final amounts for a grid of rates, in percent, and durations, in years.
+#[derive(serde::Deserialize, serde::Serialize, PartialEq, Copy, Clone)]
+#[serde(default)]
+pub struct Sensitivity
+{
+    pub rates: Axis,
+    pub years: Axis
+}
+
+impl Default for Sensitivity
+{
+    fn default() -> Self {
+        Self {
+            rates: Axis { from: 2.0, to: 12.0, step: 2.0 },
+            years: Axis { from: 5.0, to: 30.0, step: 5.0 }
+        }
+    }
+}
+
+impl Axis
+{
+    /// From the lower end up to the higher, whichever order they were given in.
+    /// Without a usable step only the ends are taken, so the table is never empty.
+    pub fn values (self) -> Vec<f64> {
+        let (low, high) = if self.from <= self.to { (self.from, self.to) } else { (self.to, self.from) };
+        if  self.step <= 0.0 || self.step.is_nan() {
+            return if high > low { vec![low, high] } else { vec![low] };
+        }
+        let mut values = Vec::new();
+        let mut n = 0.0;
+        while values.len() < MAX_STEPS {
+            let value = low + n * self.step; // Not summed up, so the steps don't drift.
+            if  value > high + 1e-9 {
+                break;
+            }
+            values.push(value);
+            n += 1.0;
+        }
+        values
+    }
+}
+
+impl Sensitivity
+{
+    // One row per rate and one column per duration.
+    pub fn table (self, start: f64) -> Vec<Vec<f64>> {
+        let years = self.years.values();
+        self.rates.values().into_iter()
+            .map(|cc| years.iter().map(|yn| start * (1.0 + cc / 100.0).powf(*yn)).collect())
+            .collect()
+    }
+}
+
+/// The heat map colors, from the lowest final amount to the highest.
+pub fn scale () -> Gradient {
+    let stops = vec![
+        (0.0, Color32::from_rgb(215, 48, 39)),
+        (0.5, Color32::from_rgb(254, 224, 139)),
+        (1.0, Color32::from_rgb(26, 152, 80))
+    ];
+    Gradient::new(stops, Interpolation::Oklab, Shape::Linear(90.0))
+}
+
+/// Where an amount falls between the lowest and the highest, on a log scale
+/// since amounts grow exponentially with the duration.
+#[allow(clippy::cast_possible_truncation)]
+pub fn heat (value: f64, low: f64, high: f64) -> f32 {
+    if  low <= 0.0 || high <= low {
+        return 0.5;
+    }
+    ((value / low).ln() / (high / low).ln()).clamp(0.0, 1.0) as f32
+}
+
+/// Black or white, whichever reads better on the fill.
+pub fn ink (fill: Color32) -> Color32 {
+    let luma = 0.299 * f32::from(fill.r()) + 0.587 * f32::from(fill.g()) + 0.114 * f32::from(fill.b());
+    if luma > 140.0 { Color32::BLACK } else { Color32::WHITE }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn values_in_either_order () {
+        assert_eq!(Axis { from: 2.0, to: 6.0, step: 2.0 }.values(), vec![2.0, 4.0, 6.0]);
+        assert_eq!(Axis { from: 6.0, to: 2.0, step: 2.0 }.values(), vec![2.0, 4.0, 6.0]);
+        assert_eq!(Axis { from: 5.0, to: 5.0, step: 1.0 }.values(), vec![5.0]);
+    }
+
+    #[test]
+    fn values_without_step () {
+        assert_eq!(Axis { from: 6.0, to: 2.0, step: 0.0 }.values(), vec![2.0, 6.0]);
+        assert_eq!(Axis { from: 2.0, to: 2.0, step: -1.0 }.values(), vec![2.0]);
+        assert_eq!(Axis { from: 0.0, to: 1e6, step: 1.0 }.values().len(), MAX_STEPS);
+    }
+}
@@ -0,0 +1,273 @@
+#![deny(clippy::pedantic)]
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![deny(unused_must_use)]
+
+use chrono::NaiveDate;
+use eframe::egui;
+
+use crate::growth::{
+    self,
+    Period
+};
+use crate::number::{
+    self,
+    NumberLocale
+};
+
+const DATE_FORMATS: [&str; 5] = ["%Y-%m-%d", "%Y/%m/%d", "%d.%m.%Y", "%m/%d/%Y", "%d/%m/%Y"];
+const DATE_COLUMNS: [&str; 3] = ["date", "datum", "time"];
+const VALUE_COLUMNS: [&str; 6] = ["adj close", "close", "price", "value", "nav", "kurs"]; // Preferred in this order.
+const BINS: usize = 20;
+
+// Why a price file couldn't be read, see `Compounder::explain_read`.
+pub enum ReadError
+{
+    File(String),
+    NoDates,
+    TooFew
+}
+
+// Dated prices from a CSV file and the part of them that is selected.
+#[derive(serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct Series
+{
+    pub path: String,
+    pub risk_free: f64, // Yearly, in percent.
+    pub window: f64,    // The length of the rolling periods, in years.
+    #[serde(skip)]
+    pub points: Vec<(NaiveDate, f64)>,
+    #[serde(skip)]
+    pub skipped: usize, // Rows of the file without a date or price.
+    #[serde(skip)]
+    pub from: usize,
+    #[serde(skip)]
+    pub to: usize
+}
+
+impl Default for Series
+{
+    fn default() -> Self {
+        Self {
+            path: String::from("prices.csv"),
+            risk_free: 0.0,
+            window: 1.0,
+            points: Vec::new(),
+            skipped: 0,
+            from: 0,
+            to: 0
+        }
+    }
+}
+
+impl Series
+{
+    /// Reads the file at `path` and selects all of it.
+    pub fn load (&mut self) -> Result<(), ReadError> {
+        let text = std::fs::read_to_string(&self.path).map_err(|e| ReadError::File(e.to_string()))?;
+        (self.points, self.skipped) = parse(&text)?;
+        self.from = 0;
+        self.to = self.points.len() - 1;
+        Ok(())
+    }
+
+    pub fn selected (&self) -> &[(NaiveDate, f64)] {
+        self.points.get(self.from..=self.to).unwrap_or_default()
+    }
+}
+
+/// Reads a date and a price from each row, taking the columns named like them in the header,
+/// or else the first two. Rows without a date or a positive price, like those with `null`
+/// or a summary at the end, are skipped and counted.
+pub fn parse (text: &str) -> Result<(Vec<(NaiveDate, f64)>, usize), ReadError> {
+    let first = text.lines().next().unwrap_or_default();
+    let delimiter = [',', '\t', ';'].into_iter().max_by_key(|d| first.matches(*d).count()).unwrap_or(',');
+    let mut rows: Vec<Vec<String>> = text.lines().filter(|line| !line.trim().is_empty()).map(|line| fields(line, delimiter)).collect();
+    let is_date = |field: &str| DATE_FORMATS.iter().any(|f| NaiveDate::parse_from_str(field, f).is_ok());
+    let (mut date, mut value) = (0, 1);
+    if  rows.first().is_some_and(|row| !row.iter().any(|field| is_date(field))) {
+        let header: Vec<String> = rows.remove(0).iter().map(|name| name.to_lowercase()).collect();
+        let find = |names: &[&str]| names.iter().find_map(|name| header.iter().position(|h| h == name));
+        date  = find(&DATE_COLUMNS).unwrap_or(date);
+        value = find(&VALUE_COLUMNS).unwrap_or(value);
+    }
+    // The format of most rows for all of them, so days and months aren't swapped halfway through.
+    let dated = |f: &str| rows.iter().filter(|row| row.get(date).is_some_and(|field| NaiveDate::parse_from_str(field, f).is_ok())).count();
+    let format = DATE_FORMATS.into_iter().rev().max_by_key(|f| dated(f)).filter(|f| dated(f) > 0).ok_or(ReadError::NoDates)?;
+    let prices: Vec<&str> = rows.iter().filter_map(|row| row.get(value).map(String::as_str)).collect();
+    let locale = numbers(&prices, delimiter);
+    let mut points: Vec<(NaiveDate, f64)> = rows.iter().filter_map(|row| {
+        let day = NaiveDate::parse_from_str(row.get(date)?, format).ok()?;
+        let price = number::parse_number(row.get(value)?, locale).filter(|price| *price > 0.0)?;
+        Some((day, price))
+    }).collect();
+    let skipped = rows.len() - points.len();
+    points.sort_by_key(|(day, _)| *day);
+    points.dedup_by_key(|(day, _)| *day);
+    if  points.len() < 2 {
+        return Err(ReadError::TooFew);
+    }
+    Ok((points, skipped))
+}
+
+// The locale to read the prices with, whatever that of the interface is. The last separator
+// of a price is decimal if the other one comes before it or three digits don't follow it,
+// and grouping if it occurs twice. Prices like `100.125` tell neither, so the decimal
+// separator is the one most prices show, or else a comma where semicolons delimit.
+fn numbers (prices: &[&str], delimiter: char) -> NumberLocale {
+    let (mut dots, mut commas) = (0, 0);
+    for price in prices {
+        let Some(i) = price.rfind(['.', ',']) else {
+            continue;
+        };
+        let (c, other) = if price[i..].starts_with('.') { ('.', ',') } else { (',', '.') };
+        let decimal = if price[..i].contains(other) || price[i + 1..].chars().filter(char::is_ascii_digit).count() != 3 {
+            c
+        } else if price.matches(c).count() > 1 {
+            other
+        } else {
+            continue;
+        };
+        if decimal == '.' { dots += 1 } else { commas += 1 }
+    }
+    match dots.cmp(&commas) {
+        std::cmp::Ordering::Greater => NumberLocale::English,
+        std::cmp::Ordering::Less    => NumberLocale::German,
+        std::cmp::Ordering::Equal   => if delimiter == ';' { NumberLocale::German } else { NumberLocale::English }
+    }
+}
+
+// Splits a line at the delimiter, except within quotes.
+fn fields (line: &str, delimiter: char) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    for c in line.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            c if c == delimiter && !quoted => fields.push(String::new()),
+            c => fields.last_mut().into_iter().for_each(|field| field.push(c))
+        }
+    }
+    fields.iter().map(|field| field.trim().to_string()).collect()
+}
+
+// The lowest and highest rate, or none for no periods.
+fn bounds (periods: &[Period]) -> Option<(f64, f64)> {
+    let low  = periods.iter().map(|(cc, _, _)| *cc).reduce(f64::min)?;
+    let high = periods.iter().map(|(cc, _, _)| *cc).reduce(f64::max)?;
+    Some((low, high))
+}
+
+/// How many periods fall in each of equally wide bins between the lowest and highest rate,
+/// with the median marked. Hovering shows the bin.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_precision_loss)]
+pub fn histogram (ui: &mut egui::Ui, periods: &[Period], color: egui::Color32, rate: impl Fn(f64) -> String) {
+    let width = ui.available_width().max(240.0);
+    let (rect, response) = ui.allocate_exact_size(egui::vec2(width, 100.0), egui::Sense::hover());
+    let Some((low, high)) = bounds(periods) else {
+        return;
+    };
+    let size = (high - low) / BINS as f64;
+    let bin = |cc: f64| if size > 0.0 { (((cc - low) / size) as usize).min(BINS - 1) } else { 0 };
+    let mut counts = [0_usize; BINS];
+    for (cc, _, _) in periods {
+        counts[bin(*cc)] += 1;
+    }
+    let most = counts.iter().copied().max().unwrap_or(1).max(1) as f32;
+    let painter = ui.painter_at(rect);
+    let slot = rect.width() / BINS as f32;
+    for (i, count) in counts.iter().enumerate() {
+        let height = rect.height() * *count as f32 / most;
+        let left = rect.left() + slot * i as f32;
+        let bar = egui::Rect::from_min_max(egui::pos2(left + 1.0, rect.bottom() - height), egui::pos2(left + slot - 1.0, rect.bottom()));
+        painter.rect_filled(bar, 0.0, color.gamma_multiply(0.7));
+    }
+    if let Some(mid) = growth::median(periods).filter(|_| size > 0.0) {
+        let x = rect.left() + rect.width() * ((mid - low) / (high - low)) as f32;
+        painter.vline(x, rect.y_range(), egui::Stroke::new(2.0, ui.visuals().warn_fg_color));
+    }
+    let font = egui::TextStyle::Small.resolve(ui.style());
+    let ink = ui.visuals().weak_text_color();
+    painter.text(rect.left_top() + egui::vec2(2.0, 2.0), egui::Align2::LEFT_TOP, rate(low), font.clone(), ink);
+    painter.text(rect.right_top() + egui::vec2(-2.0, 2.0), egui::Align2::RIGHT_TOP, rate(high), font, ink);
+    let Some(hover) = response.hover_pos() else {
+        return;
+    };
+    let i = (((hover.x - rect.left()) / slot) as usize).min(BINS - 1);
+    let from = low + size * i as f64;
+    response.on_hover_text_at_pointer(format!("{} – {}: {}", rate(from), rate(from + size), counts[i]));
+}
+
+/// The rate of each period at its start date, with a line at zero.
+/// Hovering shows the period under the pointer.
+#[allow(clippy::cast_possible_truncation)]
+pub fn rolling_chart (ui: &mut egui::Ui, periods: &[Period], color: egui::Color32, format: &str, rate: impl Fn(f64) -> String) {
+    let width = ui.available_width().max(240.0);
+    let (rect, response) = ui.allocate_exact_size(egui::vec2(width, 140.0), egui::Sense::hover());
+    let (Some((low, high)), Some(&(_, first, _)), Some(&(_, last, _))) = (bounds(periods), periods.first(), periods.last()) else {
+        return;
+    };
+    let (low, high) = (low.min(0.0), high.max(0.0));
+    let span = growth::years(first, last).max(f64::EPSILON);
+    let at = |day: NaiveDate, cc: f64| egui::pos2(
+        rect.left() + rect.width() * (growth::years(first, day) / span) as f32,
+        rect.bottom() - rect.height() * ((cc - low) / (high - low).max(f64::EPSILON)) as f32
+    );
+    let painter = ui.painter_at(rect);
+    let ink = ui.visuals().weak_text_color();
+    painter.rect_stroke(rect, 0.0, ui.visuals().widgets.noninteractive.bg_stroke);
+    painter.hline(rect.x_range(), at(first, 0.0).y, egui::Stroke::new(1.0, ink));
+    let line: Vec<egui::Pos2> = periods.iter().map(|(cc, sd, _)| at(*sd, *cc)).collect();
+    painter.add(egui::Shape::line(line, egui::Stroke::new(1.5, color)));
+    let font = egui::TextStyle::Small.resolve(ui.style());
+    painter.text(rect.left_top() + egui::vec2(4.0, 2.0), egui::Align2::LEFT_TOP, rate(high), font.clone(), ink);
+    painter.text(rect.left_bottom() + egui::vec2(4.0, -2.0), egui::Align2::LEFT_BOTTOM, first.format(format).to_string(), font.clone(), ink);
+    painter.text(rect.right_bottom() + egui::vec2(-4.0, -2.0), egui::Align2::RIGHT_BOTTOM, last.format(format).to_string(), font, ink);
+    let Some(hover) = response.hover_pos() else {
+        return;
+    };
+    let Some(&(cc, sd, fd)) = periods.iter().min_by(|a, b| (at(a.1, 0.0).x - hover.x).abs().total_cmp(&(at(b.1, 0.0).x - hover.x).abs())) else {
+        return;
+    };
+    painter.circle_filled(at(sd, cc), 3.0, color);
+    response.on_hover_text_at_pointer(format!("{} – {}: {}", sd.format(format), fd.format(format), rate(cc)));
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn prices (text: &str) -> Vec<f64> {
+        parse(text).map(|(points, _)| points.into_iter().map(|(_, price)| price).collect()).unwrap_or_default()
+    }
+
+    // With a German interface `100.125` would be a hundred thousand, but the file tells otherwise.
+    #[test]
+    fn decimals_from_the_file () {
+        assert_eq!(prices("Date,Close\n2020-01-02,100.125\n2020-01-03,100.5\n2020-01-06,\"1,234.5\"\n"), vec![100.125, 100.5, 1234.5]);
+        assert_eq!(prices("Datum;Kurs\n02.01.2020;100,125\n03.01.2020;100,5\n06.01.2020;1.234,5\n"), vec![100.125, 100.5, 1234.5]);
+        assert_eq!(prices("Date,Close\n2020-01-02,100.125\n2020-01-03,101.250\n"), vec![100.125, 101.25]);
+        assert_eq!(prices("Datum;Kurs\n02.01.2020;100,125\n03.01.2020;101,250\n"), vec![100.125, 101.25]);
+        assert_eq!(prices("Date\tClose\n2020-01-02\t1,234\n2020-01-03\t1,250.75\n"), vec![1234.0, 1250.75]);
+    }
+
+    #[test]
+    fn rows_without_date_or_price_are_skipped () {
+        let text = "Date,Adj Close\n2020-01-02,100\n2020-01-03,null\n2020-01-06,102\nTotal,302\n";
+        let (points, skipped) = parse(text).unwrap_or_default();
+        assert_eq!(points.len(), 2);
+        assert_eq!(skipped, 2);
+    }
+
+    #[test]
+    fn date_format_of_most_rows () {
+        let text = "01/02/2020,100\n01/03/2020,101\n01/13/2020,102\n";
+        let (points, skipped) = parse(text).unwrap_or_default();
+        assert_eq!(points.first().map(|(day, _)| *day), NaiveDate::from_ymd_opt(2020, 1, 2));
+        assert_eq!(skipped, 0);
+        assert!(matches!(parse("Date,Close\nsoon,100\n"), Err(ReadError::NoDates)));
+    }
+}
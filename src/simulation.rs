@@ -0,0 +1,227 @@
+#![deny(clippy::pedantic)]
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![deny(unused_must_use)]
+
+use std::sync::{
+    atomic::{
+        AtomicBool,
+        Ordering
+    },
+    mpsc,
+    Arc
+};
+
+use eframe::egui;
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng
+};
+
+pub const PERCENTILES: [u8; 5] = [5, 25, 50, 75, 95];
+const STEPS_PER_YEAR: f64 = 12.0;
+const POINTS: usize = 120;  // Percentiles are kept for at most this many points in time.
+const TAIL_DOF: u8 = 4;     // Degrees of freedom of the Student's t shocks, lower gives fatter tails.
+
+// What is simulated. Rates are yearly and in percent.
+#[derive(serde::Deserialize, serde::Serialize, PartialEq, Copy, Clone)]
+#[serde(default)]
+pub struct Model
+{
+    pub expected: f64,
+    pub volatility: f64,
+    pub fat_tails: bool,
+    pub runs: u32,
+    pub seed: u64
+}
+
+impl Default for Model
+{
+    fn default() -> Self {
+        Self {
+            expected: 7.0,
+            volatility: 15.0,
+            fat_tails: false,
+            runs: 1000,
+            seed: 1
+        }
+    }
+}
+
+// The percentiles over time, and every final amount for the probability of reaching a target.
+pub struct Outcome
+{
+    pub points: Vec<(f64, [f64; 5])>,
+    finals: Vec<f64>
+}
+
+// The model, start amount and years an outcome is for.
+type Key = (Model, f64, f64);
+
+// A run on a worker thread, stopped by setting the flag.
+struct Running
+{
+    key: Key,
+    outcome: mpsc::Receiver<Outcome>,
+    stop: Arc<AtomicBool>
+}
+
+#[derive(serde::Deserialize, serde::Serialize, Default)]
+#[serde(default)]
+pub struct Simulation
+{
+    pub model: Model,
+    pub target: String,
+    #[serde(skip)]
+    last: Option<(Key, Outcome)>,
+    #[serde(skip)]
+    running: Option<Running>
+}
+
+impl Simulation
+{
+    /// The outcome of the model from `start` over `years`, and whether it is still being worked out.
+    /// Runs take a while with many of them over many years, so they are made on a worker thread,
+    /// showing the last outcome meanwhile. A run for inputs changed since is stopped.
+    pub fn outcome (&mut self, context: &egui::Context, start: f64, years: f64) -> (Option<&Outcome>, bool) {
+        let key = (self.model, start, years);
+        if let Some(running) = self.running.take() {
+            match running.outcome.try_recv() {
+                Ok(outcome) => self.last = Some((running.key, outcome)),
+                Err(mpsc::TryRecvError::Empty) if running.key == key => self.running = Some(running),
+                Err(_) => running.stop.store(true, Ordering::Relaxed)
+            }
+        }
+        if  self.running.is_none() && self.last.as_ref().is_none_or(|(last, _)| *last != key) {
+            let (sender, outcome) = mpsc::channel();
+            let stop = Arc::new(AtomicBool::new(false));
+            let (context, stopped) = (context.clone(), stop.clone());
+            std::thread::spawn(move || {
+                if let Some(outcome) = simulate(key.0, start, years, &stopped) {
+                    sender.send(outcome).ok();
+                    context.request_repaint();
+                }
+            });
+            self.running = Some(Running { key, outcome, stop });
+        }
+        (self.last.as_ref().map(|(_, outcome)| outcome), self.running.is_some())
+    }
+}
+
+impl Outcome
+{
+    /// The share of runs ending at or above the target.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn probability (&self, target: f64) -> f64 {
+        if  self.finals.is_empty() {
+            return 0.0;
+        }
+        let below = self.finals.partition_point(|fv| *fv < target);
+        (self.finals.len() - below) as f64 / self.finals.len() as f64
+    }
+}
+
+// Geometric Brownian motion in monthly steps, with the drift set so the mean grows at the expected rate.
+// Gives up, between steps, once `stop` is set.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_precision_loss)]
+fn simulate (model: Model, start: f64, years: f64, stop: &AtomicBool) -> Option<Outcome> {
+    let mut rng = StdRng::seed_from_u64(model.seed);
+    let steps = ((years * STEPS_PER_YEAR).ceil() as usize).max(1);
+    let dt = years / steps as f64;
+    let sigma = model.volatility / 100.0;
+    let drift = ((1.0 + model.expected / 100.0).max(f64::MIN_POSITIVE).ln() - 0.5 * sigma * sigma) * dt;
+    let spread = sigma * dt.sqrt();
+    let stride = steps.div_ceil(POINTS);
+    let mut values = vec![start; model.runs.max(1) as usize];
+    let mut points = vec![(0.0, [start; 5])];
+    for step in 1..=steps {
+        if  stop.load(Ordering::Relaxed) {
+            return None;
+        }
+        for value in &mut values {
+            let shock = if model.fat_tails { student(&mut rng) } else { normal(&mut rng) };
+            *value *= (drift + spread * shock).exp();
+        }
+        if  step % stride == 0 || step == steps {
+            let mut sorted = values.clone();
+            sorted.sort_by(f64::total_cmp);
+            points.push((step as f64 * dt, PERCENTILES.map(|p| percentile(&sorted, p))));
+        }
+    }
+    values.sort_by(f64::total_cmp);
+    Some(Outcome { points, finals: values })
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_precision_loss)]
+fn percentile (sorted: &[f64], p: u8) -> f64 {
+    let at = (f64::from(p) / 100.0 * (sorted.len() - 1) as f64).round() as usize;
+    sorted.get(at).copied().unwrap_or_default()
+}
+
+// Box-Muller, from two uniform numbers in (0, 1].
+fn normal (rng: &mut StdRng) -> f64 {
+    let u1 = 1.0 - rng.gen::<f64>();
+    let u2 = rng.gen::<f64>();
+    (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+}
+
+// Student's t, scaled to unit variance so only the tails differ from the normal.
+fn student (rng: &mut StdRng) -> f64 {
+    let dof = f64::from(TAIL_DOF);
+    let chi: f64 = (0..TAIL_DOF).map(|_| normal(rng).powi(2)).sum();
+    normal(rng) / (chi / dof).sqrt() * ((dof - 2.0) / dof).sqrt()
+}
+
+/// Draws the bands between the percentiles, the median and the target, if any,
+/// and shows the percentiles of the point in time under the pointer.
+pub fn chart (ui: &mut egui::Ui, outcome: &Outcome, target: Option<f64>, color: egui::Color32, years: &str, amount: impl Fn(f64) -> String) {
+    let width = ui.available_width().max(240.0);
+    let (rect, response) = ui.allocate_exact_size(egui::vec2(width, 180.0), egui::Sense::hover());
+    let (Some(&(last, _)), Some(top)) = (outcome.points.last(), outcome.points.iter().map(|(_, ps)| ps[4]).reduce(f64::max)) else {
+        return;
+    };
+    let top = top.max(target.unwrap_or(0.0)) * 1.05;
+    #[allow(clippy::cast_possible_truncation)]
+    let at = |yn: f64, fv: f64| egui::pos2(
+        rect.left() + rect.width() * (yn / last.max(f64::EPSILON)) as f32,
+        rect.bottom() - rect.height() * (fv / top.max(f64::EPSILON)).clamp(0.0, 1.0) as f32
+    );
+    let painter = ui.painter_at(rect);
+    painter.rect_stroke(rect, 0.0, ui.visuals().widgets.noninteractive.bg_stroke);
+    for (low, high, alpha) in [(0, 4, 0.2), (1, 3, 0.4)] {
+        let mut mesh = egui::Mesh::default();
+        let fill = color.gamma_multiply(alpha);
+        for (yn, ps) in &outcome.points {
+            let n = u32::try_from(mesh.vertices.len()).unwrap_or(0);
+            mesh.colored_vertex(at(*yn, ps[low]), fill);
+            mesh.colored_vertex(at(*yn, ps[high]), fill);
+            if  n > 0 {
+                mesh.add_triangle(n - 2, n - 1, n);
+                mesh.add_triangle(n - 1, n, n + 1);
+            }
+        }
+        painter.add(mesh);
+    }
+    let median: Vec<egui::Pos2> = outcome.points.iter().map(|(yn, ps)| at(*yn, ps[2])).collect();
+    painter.add(egui::Shape::line(median, egui::Stroke::new(2.0, color)));
+    if let Some(target) = target {
+        let stroke = egui::Stroke::new(1.0, ui.visuals().warn_fg_color);
+        painter.extend(egui::Shape::dashed_line(&[at(0.0, target), at(last, target)], stroke, 4.0, 4.0));
+    }
+    let font = egui::TextStyle::Small.resolve(ui.style());
+    let ink = ui.visuals().weak_text_color();
+    painter.text(rect.left_top() + egui::vec2(4.0, 2.0), egui::Align2::LEFT_TOP, amount(top), font.clone(), ink);
+    painter.text(rect.left_bottom() + egui::vec2(4.0, -2.0), egui::Align2::LEFT_BOTTOM, amount(0.0), font, ink);
+    let Some(hover) = response.hover_pos() else {
+        return;
+    };
+    let Some((yn, ps)) = outcome.points.iter().min_by(|a, b| (at(a.0, 0.0).x - hover.x).abs().total_cmp(&(at(b.0, 0.0).x - hover.x).abs())) else {
+        return;
+    };
+    let x = at(*yn, 0.0).x;
+    painter.vline(x, rect.y_range(), egui::Stroke::new(1.0, ink));
+    let text: Vec<String> = PERCENTILES.iter().zip(ps).rev().map(|(p, fv)| format!("{p} %: {}", amount(*fv))).collect();
+    response.on_hover_text_at_pointer(format!("{yn:.1} {years}\n{}", text.join("\n")));
+}
@@ -0,0 +1,64 @@
+#![deny(clippy::pedantic)]
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![deny(unused_must_use)]
+
+use eframe::egui::Color32;
+
+use compounder_widgets::gradient::{
+    Interpolation,
+    Shape
+};
+
+#[derive(serde::Deserialize, serde::Serialize, PartialEq, Clone)]
+#[serde(default)]
+pub struct Theme
+{
+    pub accent: Color32,
+    pub dark_background: Color32,
+    pub dark_text: Color32,
+    pub light_background: Color32,
+    pub light_text: Color32,
+    pub gradient: bool,
+    pub dark_gradient: Color32,  // Panels fade from the background color at the top to these at the bottom.
+    pub light_gradient: Color32,
+    pub gradient_shape: Shape,
+    pub gradient_interpolation: Interpolation,
+    pub rounding: f32,
+    pub spacing: f32
+}
+
+impl Default for Theme
+{
+    fn default() -> Self {
+        Self {
+            accent: Color32::from_rgb(170, 0, 204),
+            dark_background: Color32::from_rgb(20, 20, 20),
+            dark_text: Color32::from_gray(255),
+            light_background: Color32::from_rgb(250, 250, 250),
+            light_text: Color32::from_gray(0),
+            gradient: false,
+            dark_gradient: Color32::from_rgb(50, 30, 56),
+            light_gradient: Color32::from_rgb(186, 167, 190),
+            gradient_shape: Shape::Linear(180.0),
+            gradient_interpolation: Interpolation::Gamma,
+            rounding: 2.0,
+            spacing: 16.0
+        }
+    }
+}
+
+impl Theme
+{
+    /// Reads a theme file written by [`Theme::export`].
+    pub fn import (path: &str) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        ron::from_str(&text).map_err(|e| e.to_string())
+    }
+
+    pub fn export (&self, path: &str) -> Result<(), String> {
+        let text = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default()).map_err(|e| e.to_string())?;
+        std::fs::write(path, text).map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,127 @@
+// Shows every widget of the crate with its options.
+// Run with `cargo run -p compounder-widgets-gallery`.
+
+use compounder_widgets::{
+    gradient::{
+        self,
+        Gradient,
+        Interpolation,
+        Shape,
+        TextureManager
+    },
+    ErrorField,
+    NumberField,
+    Switch
+};
+use eframe::egui;
+
+struct Gallery
+{
+    on: bool,
+    mixed: Option<bool>,
+    date: String,
+    number: String,
+    code: String,
+    rate: String,
+    angle: f32,
+    interpolation: Interpolation,
+    textures: TextureManager
+}
+
+impl Default for Gallery
+{
+    fn default() -> Self {
+        Self {
+            on: true,
+            mixed: None,
+            date: String::from("2025-12-31"),
+            number: String::from("12.5"),
+            code: String::from("SEK"),
+            rate: String::from("7.0 %"),
+            angle: 90.0,
+            interpolation: Interpolation::Oklab,
+            textures: TextureManager::default()
+        }
+    }
+}
+
+impl Gallery
+{
+    fn show_switches (&mut self, ui: &mut egui::Ui) {
+        ui.heading("Switch");
+        ui.horizontal(|ui| {
+            ui.add(Switch::bind(&mut self.on).label("bound"));
+            ui.add(Switch::tri(&mut self.mixed).label("tri-state"));
+            if ui.button("mixed").clicked() {
+                self.mixed = None;
+            }
+            ui.add(Switch::bind(&mut self.on).texts("on", "off").size(28.0));
+            ui.add(Switch::bind(&mut self.on).colors(egui::Color32::from_rgb(0, 150, 80), egui::Color32::WHITE));
+            ui.add_enabled(false, Switch::bind(&mut self.on).label("disabled"));
+        });
+    }
+
+    fn show_fields (&mut self, ui: &mut egui::Ui) {
+        ui.heading("ErrorField");
+        egui::Grid::new("fields").num_columns(2).show(ui, |ui| {
+            ui.label("Date with mask, try the arrow keys");
+            ui.add(ErrorField::new(&mut self.date).mask("YYYY-MM-DD").hint("YYYY-MM-DD").numeric()
+                .validate(|text| if text.len() == 10 { Ok(()) } else { Err("Type all eight digits") }));
+            ui.end_row();
+            ui.label("Number, message in tooltip");
+            ui.add(ErrorField::new(&mut self.number).hint("0.0").numeric().validate(|text| text.parse::<f64>()));
+            ui.end_row();
+            ui.label("Currency code, message inline");
+            ui.add(ErrorField::new(&mut self.code).width(60.0).inline(true)
+                .validate(|text| if text.len() == 3 && text.chars().all(|c| c.is_ascii_uppercase()) { Ok(()) } else { Err("Three capital letters") }));
+            ui.end_row();
+            ui.label("Rate above -100 %, drag or step with shift or ctrl");
+            ui.add(NumberField::new(&mut self.rate).width(80.0).step(0.1).range(-99.9..=1000.0)
+                .parse(|text| text.trim().trim_end_matches('%').trim().parse().ok())
+                .format(|value| format!("{value:.1} %")));
+            ui.end_row();
+        });
+    }
+
+    fn show_gradients (&mut self, ui: &mut egui::Ui) {
+        ui.heading("Gradient");
+        ui.horizontal(|ui| {
+            ui.add(egui::Slider::new(&mut self.angle, 0.0..=360.0).text("angle"));
+            ui.selectable_value(&mut self.interpolation, Interpolation::Gamma,  "sRGB");
+            ui.selectable_value(&mut self.interpolation, Interpolation::Linear, "linear light");
+            ui.selectable_value(&mut self.interpolation, Interpolation::Oklab,  "Oklab");
+        });
+        let stops = vec![(0.0, egui::Color32::from_rgb(170, 0, 204)), (0.5, egui::Color32::from_rgb(250, 230, 255)), (1.0, egui::Color32::from_rgb(0, 120, 200))];
+        ui.horizontal(|ui| {
+            for shape in [Shape::Linear(self.angle), Shape::Radial] {
+                let (rect, _) = ui.allocate_exact_size(egui::vec2(200.0, 120.0), egui::Sense::hover());
+                let fill = Gradient::new(stops.clone(), self.interpolation, shape);
+                let texture = self.textures.get(ui.ctx(), &fill);
+                gradient::paint(ui.painter(), rect, &fill, texture);
+            }
+        });
+        let stats = self.textures.stats();
+        ui.label(format!("{} textures, {} bytes, {} hits, {} misses, {} evictions", stats.textures, stats.bytes, stats.hits, stats.misses, stats.evictions));
+    }
+}
+
+impl eframe::App for Gallery
+{
+    fn update (&mut self, context: &egui::Context, _frame: &mut eframe::Frame) {
+        egui::CentralPanel::default().show(context, |ui| {
+            self.show_switches(ui);
+            ui.separator();
+            self.show_fields(ui);
+            ui.separator();
+            self.show_gradients(ui);
+        });
+    }
+}
+
+fn main () -> eframe::Result {
+    let options = eframe::NativeOptions {
+        viewport: egui::ViewportBuilder::default().with_inner_size([480.0, 480.0]),
+        ..Default::default()
+    };
+    eframe::run_native("Widget gallery", options, Box::new(|_| Ok(Box::<Gallery>::default())))
+}
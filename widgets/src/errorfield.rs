@@ -0,0 +1,313 @@
+#![deny(clippy::pedantic)]
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![deny(unused_must_use)]
+
+use std::fmt::Display;
+
+use egui::Widget;
+use egui::text::{
+    CCursor,
+    CCursorRange
+};
+
+/// A single line text field that shows why its text is invalid, in a tooltip
+/// or below the field, and tells screen readers the same.
+pub struct ErrorField<'a>
+{
+    value: &'a mut String,
+    error: Option<String>,
+    hint: &'a str,
+    width: Option<f32>,
+    inline: bool,
+    numeric: bool,
+    mask: &'a str,
+    label: &'a str,
+    id: Option<egui::Id>
+}
+
+impl<'a> ErrorField<'a>
+{
+    pub const fn new (value: &'a mut String) -> Self {
+        Self {
+            value,
+            error: None,
+            hint: "",
+            width: None,
+            inline: false,
+            numeric: false,
+            mask: "",
+            label: "",
+            id: None
+        }
+    }
+
+    /// Checks the current text, an error makes the field invalid and is shown as its message.
+    #[must_use]
+    pub fn validate<T, E: Display> (self, validator: impl FnOnce(&str) -> Result<T, E>) -> Self {
+        let error = validator(self.value).err();
+        self.error(error)
+    }
+
+    /// For errors found elsewhere, like those depending on other fields. The first error is kept.
+    #[must_use]
+    pub fn error<E: Display> (mut self, error: Option<E>) -> Self {
+        if  self.error.is_none() {
+            self.error = error.map(|e| e.to_string());
+        }
+        self
+    }
+
+    /// Shown greyed out while the field is empty.
+    #[must_use]
+    pub const fn hint (mut self, hint: &'a str) -> Self {
+        self.hint = hint;
+        self
+    }
+
+    #[must_use]
+    pub const fn width (mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Shows the message below the field instead of in a tooltip.
+    #[must_use]
+    pub const fn inline (mut self, inline: bool) -> Self {
+        self.inline = inline;
+        self
+    }
+
+    /// Shows the value in the monospace font, so digits line up between fields.
+    #[must_use]
+    pub const fn numeric (mut self) -> Self {
+        self.numeric = true;
+        self
+    }
+
+    /// Restricts typing to a pattern like `YYYY-MM-DD`, where `Y`, `M` and `D` take the digits
+    /// of a year, month and day, `#` takes any digit and other characters are separators.
+    /// Separators are filled in, impossible digits are dropped, and the arrow keys
+    /// step the part under the cursor up or down.
+    #[must_use]
+    pub const fn mask (mut self, mask: &'a str) -> Self {
+        self.mask = mask;
+        self
+    }
+
+    /// The name screen readers give the field, usually its caption.
+    #[must_use]
+    pub const fn label (mut self, label: &'a str) -> Self {
+        self.label = label;
+        self
+    }
+
+    /// The id of the text edit, for widgets that need to know whether it has focus.
+    #[must_use]
+    pub const fn id (mut self, id: egui::Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+}
+
+fn reflect (error: Option<&String>, ui: &mut egui::Ui) {
+    if  error.is_some() {
+        let visuals = &mut ui.style_mut().visuals;
+        visuals.widgets.inactive.bg_stroke = egui::Stroke::new(1.0, visuals.error_fg_color);
+        visuals.widgets.hovered.bg_stroke = egui::Stroke::new(2.0, visuals.error_fg_color);
+        visuals.selection.stroke = egui::Stroke::new(1.0, visuals.error_fg_color);
+    }
+}
+
+fn mark (error: Option<&String>, inline: bool, ui: &egui::Ui, response: egui::Response) -> egui::Response {
+    let Some(error) = error else {
+        return response;
+    };
+    let font = egui::TextStyle::Button.resolve(ui.style());
+    let spot = response.rect.right_center() - egui::vec2(4.0, 0.0);
+    ui.painter().text(spot, egui::Align2::RIGHT_CENTER, "!", font, ui.visuals().error_fg_color); // Don't rely on color alone.
+    ui.ctx().accesskit_node_builder(response.id, |node| {
+        node.set_invalid(egui::accesskit::Invalid::True);
+        if !error.is_empty() {
+            node.set_description(error.as_str());
+        }
+    });
+    if  error.is_empty() || inline {
+        return response;
+    }
+    response.on_hover_text(error)
+}
+
+impl Widget for ErrorField<'_>
+{
+    fn ui (self, ui: &mut egui::Ui) -> egui::Response {
+        let Self { value, error, hint, width, inline, numeric, mask, label, id } = self;
+        ui.vertical(|ui| {
+            reflect(error.as_ref(), ui);
+            let style = if numeric { egui::TextStyle::Monospace } else { egui::TextStyle::Body };
+            let width = width.unwrap_or(ui.spacing().text_edit_width);
+            let id = id.unwrap_or_else(|| ui.next_auto_id());
+            let stepped = !mask.is_empty() && ui.memory(|m| m.has_focus(id)) && step(ui.ctx(), id, value, mask);
+            let mut response = egui::TextEdit::singleline(value).id(id).font(style).hint_text(hint).desired_width(width).ui(ui).highlight();
+            if !mask.is_empty() && response.changed() {
+                conform(ui.ctx(), id, value, mask);
+            }
+            if  stepped {
+                response.mark_changed();
+            }
+            if !label.is_empty() {
+                ui.ctx().accesskit_node_builder(response.id, |node| node.set_label(label));
+            }
+            let response = mark(error.as_ref(), inline, ui, response);
+            if let Some(error) = error.filter(|_| inline) {
+                ui.set_max_width(width);
+                ui.add(egui::Label::new(egui::RichText::new(error).small().color(ui.visuals().error_fg_color)).wrap());
+            }
+            response
+        }).inner
+    }
+}
+
+// Puts the digits of `value` into the slots of `mask`, skipping any that can't start
+// a valid part, and keeps the cursor after the same digit as before.
+fn conform (context: &egui::Context, id: egui::Id, value: &mut String, mask: &str) {
+    let mut state = egui::TextEdit::load_state(context, id).unwrap_or_default();
+    let cursor = state.cursor.char_range().map_or(usize::MAX, |range| range.primary.index);
+    let slots: Vec<char> = mask.chars().filter(|c| is_slot(*c)).collect();
+    let mut digits = String::new();
+    let mut before = 0; // Digits kept before the cursor.
+    for (at, c) in value.chars().enumerate() {
+        if  c.is_ascii_digit() && digits.len() < slots.len() {
+            digits.push(c);
+            if  possible(&format_digits(&digits, mask), mask) {
+                before += usize::from(at < cursor);
+            } else {
+                digits.pop();
+            }
+        }
+    }
+    *value = format_digits(&digits, mask);
+    let mut index = value.chars().count();
+    if  before < digits.len() {
+        index = value.chars().enumerate().filter(|(_, c)| c.is_ascii_digit()).nth(before).map_or(index, |(i, _)| i);
+    }
+    state.cursor.set_char_range(Some(CCursorRange::one(CCursor::new(index))));
+    state.store(context, id);
+}
+
+fn format_digits (digits: &str, mask: &str) -> String {
+    let mut text = String::new();
+    let mut rest = digits.chars().peekable();
+    for m in mask.chars() {
+        if  rest.peek().is_none() {
+            break;
+        }
+        if  is_slot(m) {
+            text.extend(rest.next());
+        } else {
+            text.push(m); // Only between digits, so backspace can remove it.
+        }
+    }
+    text
+}
+
+// Whether every part typed so far, also those half typed, can still become valid.
+fn possible (text: &str, mask: &str) -> bool {
+    let parts = parts(text, mask);
+    let days = month_days(&parts, mask);
+    parts.iter().all(|&(kind, part, _)| {
+        let value: u32 = part.parse().unwrap_or(0);
+        match (kind, part.len()) {
+            ('M', 1) => value <= 1,
+            ('M', _) => (1..=12).contains(&value),
+            ('D', 1) => value <= days / 10,
+            ('D', _) => (1..=days).contains(&value),
+            _ => true
+        }
+    })
+}
+
+// The number of days in the month, once it is complete, and in February the year too.
+// Until then the longest month it can be.
+fn month_days (parts: &[(char, &str, usize)], mask: &str) -> u32 {
+    let complete = |kind: char| parts.iter()
+        .find(|(k, part, start)| *k == kind && part.chars().count() == width(mask, *start))
+        .and_then(|(_, part, _)| part.parse::<u32>().ok());
+    match (complete('M'), complete('Y')) {
+        (Some(2), Some(year)) if year % 4 != 0 || (year % 100 == 0 && year % 400 != 0) => 28,
+        (Some(2), _) => 29,
+        (Some(4 | 6 | 9 | 11), _) => 30,
+        _ => 31
+    }
+}
+
+// The number of slots of the part starting at `start`.
+fn width (mask: &str, start: usize) -> usize {
+    let mut slots = mask.chars().skip(start).peekable();
+    let kind = slots.peek().copied();
+    slots.take_while(|c| Some(*c) == kind).count()
+}
+
+// The kind, text and char position of each part of `text`, as laid out by `mask`.
+// Positions count chars, as separators need not be ASCII, like in `YYYY年MM月DD`.
+fn parts<'t> (text: &'t str, mask: &str) -> Vec<(char, &'t str, usize)> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mask: Vec<char> = mask.chars().collect();
+    let count = text.chars().count();
+    while start < mask.len() {
+        let kind = mask[start];
+        let end = mask[start..].iter().position(|c| *c != kind).map_or(mask.len(), |n| start + n);
+        if  is_slot(kind) && start < count {
+            parts.push((kind, &text[byte(text, start)..byte(text, end.min(count))], start));
+        }
+        start = end;
+    }
+    parts
+}
+
+// The byte offset of the char at `at`, or the end of the text.
+fn byte (text: &str, at: usize) -> usize {
+    text.char_indices().nth(at).map_or(text.len(), |(i, _)| i)
+}
+
+// Steps the complete part under the cursor, wrapping months and days around.
+fn step (context: &egui::Context, id: egui::Id, value: &mut String, mask: &str) -> bool {
+    let delta: i64 = context.input_mut(|i| {
+        if i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp) { 1 } else if i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown) { -1 } else { 0 }
+    });
+    let cursor = egui::TextEdit::load_state(context, id).and_then(|state| state.cursor.char_range()).map_or(0, |range| range.primary.index);
+    if  delta == 0 {
+        return false;
+    }
+    let Some((kind, part, start)) = parts(value, mask).into_iter().find(|(_, part, start)| (*start..=start + part.chars().count()).contains(&cursor)) else {
+        return false;
+    };
+    let width = width(mask, start);
+    let Ok(old) = part.parse::<i64>() else {
+        return false;
+    };
+    if  part.chars().count() < width {
+        return false;
+    }
+    let (low, high) = match kind {
+        'M' => (1, 12),
+        'D' => (1, i64::from(month_days(&parts(value, mask), mask))),
+        _ => (0, 10_i64.pow(u32::try_from(width).unwrap_or(0)) - 1)
+    };
+    let new = (old - low + delta).rem_euclid(high - low + 1) + low;
+    value.replace_range(byte(value, start)..byte(value, start + width), &format!("{new:0width$}"));
+    // A day beyond the end of the month stepped to moves back to its last day.
+    let days = month_days(&parts(value, mask), mask);
+    let over = parts(value, mask).into_iter().find(|(kind, part, _)| *kind == 'D' && part.parse::<u32>().is_ok_and(|day| day > days));
+    if let Some((_, part, start)) = over {
+        let width = part.chars().count();
+        value.replace_range(byte(value, start)..byte(value, start + width), &format!("{days:0width$}"));
+    }
+    true
+}
+
+const fn is_slot (c: char) -> bool {
+    matches!(c, 'Y' | 'M' | 'D' | '#')
+}
@@ -0,0 +1,271 @@
+#![deny(clippy::pedantic)]
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![deny(unused_must_use)]
+
+use std::hash::{
+    Hash,
+    Hasher
+};
+
+use egui::{
+    ahash::HashMap,
+    epaint,
+    pos2,
+    Color32,
+    Rect,
+    Rgba,
+    TextureHandle,
+    TextureOptions
+};
+
+const ROW: usize = 256;  // Width of the texture for linear gradients, which is one pixel high.
+const DISC: usize = 128; // Width and height of the texture for radial gradients.
+const CAPACITY: usize = 32;         // Most textures kept, the least recently used go first.
+const BUDGET: usize = 4 * 1024 * 1024; // Most bytes kept.
+
+/// How colors are mixed between two stops.
+#[derive(PartialEq, Eq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Interpolation
+{
+    Linear, // In linear light.
+    Gamma,  // In sRGB, which is what most design tools do.
+    Oklab   // Perceptually even steps in lightness and hue.
+}
+
+#[derive(PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Shape
+{
+    /// Angle in degrees as in CSS: 0 runs bottom to top, 90 left to right, 180 top to bottom.
+    Linear(f32),
+    /// From the center outwards, reaching the last stop at the middle of each edge.
+    Radial
+}
+
+#[derive(PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Gradient
+{
+    pub stops: Vec<(f32, Color32)>, // Positions from 0 to 1, in increasing order.
+    pub interpolation: Interpolation,
+    pub shape: Shape
+}
+
+impl Gradient
+{
+    #[must_use]
+    pub fn new (mut stops: Vec<(f32, Color32)>, interpolation: Interpolation, shape: Shape) -> Self {
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self { stops, interpolation, shape }
+    }
+
+    /// The color at `t`, where 0 is the start and 1 the end of the gradient.
+    #[must_use]
+    pub fn sample (&self, t: f32) -> Color32 {
+        let Some(&(first, fc)) = self.stops.first() else {
+            return Color32::TRANSPARENT;
+        };
+        if  t <= first {
+            return fc;
+        }
+        for pair in self.stops.windows(2) {
+            let ((p0, c0), (p1, c1)) = (pair[0], pair[1]);
+            if  t <= p1 {
+                let s = if p1 > p0 { (t - p0) / (p1 - p0) } else { 1.0 };
+                return self.mix(c0, c1, s);
+            }
+        }
+        self.stops.last().map_or(fc, |&(_, lc)| lc)
+    }
+
+    fn mix (&self, c0: Color32, c1: Color32, s: f32) -> Color32 {
+        match self.interpolation {
+            Interpolation::Gamma  => c0.lerp_to_gamma(c1, s),
+            Interpolation::Linear => (Rgba::from(c0) * (1.0 - s) + Rgba::from(c1) * s).into(),
+            Interpolation::Oklab  => {
+                let (l0, a0, b0) = oklab(Rgba::from(c0));
+                let (l1, a1, b1) = oklab(Rgba::from(c1));
+                let alpha = Rgba::from(c0).a() * (1.0 - s) + Rgba::from(c1).a() * s;
+                from_oklab(l0 + (l1 - l0) * s, a0 + (a1 - a0) * s, b0 + (b1 - b0) * s, alpha).into()
+            }
+        }
+    }
+
+    /// A row of pixels for linear gradients, which are turned to their angle when painted,
+    /// and a square for radial ones, which is stretched to the painted rectangle.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn image (&self) -> epaint::ColorImage {
+        let size = self.size();
+        let pixels = match self.shape {
+            Shape::Linear(_) => (0..ROW).map(|i| self.sample(i as f32 / (ROW - 1) as f32)).collect(),
+            Shape::Radial => {
+                let at = |i: usize| (i as f32 + 0.5) / DISC as f32 * 2.0 - 1.0;
+                (0..DISC * DISC).map(|i| self.sample(at(i % DISC).hypot(at(i / DISC)))).collect()
+            }
+        };
+        epaint::ColorImage { size, pixels }
+    }
+
+    #[must_use]
+    pub const fn size (&self) -> [usize; 2] {
+        match self.shape {
+            Shape::Linear(_) => [ROW, 1],
+            Shape::Radial    => [DISC, DISC]
+        }
+    }
+
+    const fn bytes (&self) -> usize {
+        let [w, h] = self.size();
+        w * h * std::mem::size_of::<Color32>()
+    }
+
+    // Everything the texture depends on. The angle doesn't, it is applied when painting.
+    fn key (&self) -> Key {
+        Key {
+            stops: self.stops.iter().map(|&(p, c)| (p.to_bits(), c)).collect(),
+            interpolation: self.interpolation,
+            radial: self.shape == Shape::Radial
+        }
+    }
+}
+
+#[derive(Hash, PartialEq, Eq, Clone)]
+struct Key
+{
+    stops: Vec<(u32, Color32)>,
+    interpolation: Interpolation,
+    radial: bool
+}
+
+impl Key
+{
+    // Shown in egui's texture list, so tells textures apart.
+    fn name (&self) -> String {
+        let mut hasher = std::hash::DefaultHasher::new();
+        self.hash(&mut hasher);
+        format!("gradient-{}-{:016x}", if self.radial { "radial" } else { "linear" }, hasher.finish())
+    }
+}
+
+struct Entry
+{
+    texture: TextureHandle,
+    bytes: usize,
+    used: u64
+}
+
+#[derive(Default, Copy, Clone)]
+pub struct Stats
+{
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+    pub textures: usize,
+    pub bytes: usize
+}
+
+// Uploads each gradient once, and frees the least recently used textures
+// when there are more than `CAPACITY` of them or they take more than `BUDGET` bytes.
+#[derive(Default)]
+pub struct TextureManager
+{
+    entries: HashMap<Key, Entry>,
+    tick: u64,
+    stats: Stats
+}
+
+impl TextureManager
+{
+    pub fn get(&mut self, ctx: &egui::Context, gradient: &Gradient) -> &TextureHandle {
+        self.tick += 1;
+        let key = gradient.key();
+        if  self.entries.contains_key(&key) {
+            self.stats.hits += 1;
+        } else {
+            self.stats.misses += 1;
+            self.make_room(gradient.bytes());
+        }
+        let stats = &mut self.stats;
+        let entry = self.entries.entry(key).or_insert_with_key(|key| {
+            stats.bytes += gradient.bytes();
+            Entry {
+                texture: ctx.load_texture(key.name(), gradient.image(), TextureOptions::LINEAR),
+                bytes: gradient.bytes(),
+                used: 0
+            }
+        });
+        entry.used = self.tick;
+        &entry.texture
+    }
+
+    #[must_use]
+    pub fn stats (&self) -> Stats {
+        Stats { textures: self.entries.len(), ..self.stats }
+    }
+
+    // Dropping the last handle to a texture frees it in egui.
+    fn make_room (&mut self, bytes: usize) {
+        while self.entries.len() >= CAPACITY || (!self.entries.is_empty() && self.stats.bytes + bytes > BUDGET) {
+            let Some(oldest) = self.entries.iter().min_by_key(|(_, e)| e.used).map(|(k, _)| k.clone()) else {
+                break;
+            };
+            if let Some(entry) = self.entries.remove(&oldest) {
+                self.stats.bytes -= entry.bytes;
+                self.stats.evictions += 1;
+            }
+        }
+    }
+}
+
+/// Fills `rect` with `gradient`, using the texture made for it by [`TextureManager`].
+pub fn paint (painter: &egui::Painter, rect: Rect, gradient: &Gradient, texture: &TextureHandle) {
+    let mut mesh = epaint::Mesh::with_texture(texture.id());
+    let corners = [rect.left_top(), rect.right_top(), rect.right_bottom(), rect.left_bottom()];
+    let uvs = match gradient.shape {
+        Shape::Radial => [pos2(0.0, 0.0), pos2(1.0, 0.0), pos2(1.0, 1.0), pos2(0.0, 1.0)],
+        Shape::Linear(angle) => {
+            // Project the corners on the direction, so the farthest corners get the first and last stop.
+            let (dx, dy) = angle.to_radians().sin_cos();
+            let dy = -dy;
+            let reach = (dx.abs() * rect.width() + dy.abs() * rect.height()).max(f32::EPSILON);
+            corners.map(|pos| {
+                let off = pos - rect.center();
+                pos2(0.5 + (off.x * dx + off.y * dy) / reach, 0.5)
+            })
+        }
+    };
+    for (pos, uv) in corners.into_iter().zip(uvs) {
+        mesh.vertices.push(epaint::Vertex { pos, uv, color: Color32::WHITE });
+    }
+    mesh.add_triangle(0, 1, 2);
+    mesh.add_triangle(0, 2, 3);
+    painter.add(mesh);
+}
+
+// Conversions from https://bottosson.github.io/posts/oklab/
+fn oklab (c: Rgba) -> (f32, f32, f32) {
+    let l = (0.412_221_47 * c.r() + 0.536_332_5 * c.g() + 0.051_445_99 * c.b()).cbrt();
+    let m = (0.211_903_5 * c.r() + 0.680_699_5 * c.g() + 0.107_396_96 * c.b()).cbrt();
+    let s = (0.088_302_46 * c.r() + 0.281_718_85 * c.g() + 0.629_978_7 * c.b()).cbrt();
+    (
+        0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+        1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+        0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s
+    )
+}
+
+fn from_oklab (l: f32, a: f32, b: f32, alpha: f32) -> Rgba {
+    let lc = (l + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+    let mc = (l - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+    let sc = (l - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+    Rgba::from_rgba_premultiplied(
+        (4.076_741_7 * lc - 3.307_711_6 * mc + 0.230_969_94 * sc).max(0.0),
+        (-1.268_438 * lc + 2.609_757_4 * mc - 0.341_319_38 * sc).max(0.0),
+        (-0.004_196_086_3 * lc - 0.703_418_6 * mc + 1.707_614_7 * sc).max(0.0),
+        alpha
+    )
+}
@@ -0,0 +1,29 @@
+#![deny(clippy::pedantic)]
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![deny(unused_must_use)]
+
+//! Widgets for [egui](https://github.com/emilk/egui) made for Compounder:
+//! an animated on/off [`Switch`], a text field with validation, an [`ErrorField`],
+//! a [`NumberField`] to drag or step, and [`gradient`] backgrounds with a texture cache.
+//! Each comes behind a feature of the same name, all enabled by default.
+//! The `serde` feature makes gradients serializable, for storing them in themes.
+//!
+//! Run `cargo run -p compounder-widgets-gallery` to see them.
+
+#[cfg(feature = "switch")]
+pub mod switch;
+#[cfg(feature = "errorfield")]
+pub mod errorfield;
+#[cfg(feature = "numberfield")]
+pub mod numberfield;
+#[cfg(feature = "gradient")]
+pub mod gradient;
+
+#[cfg(feature = "switch")]
+pub use switch::Switch;
+#[cfg(feature = "errorfield")]
+pub use errorfield::ErrorField;
+#[cfg(feature = "numberfield")]
+pub use numberfield::NumberField;
@@ -0,0 +1,210 @@
+#![deny(clippy::pedantic)]
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![deny(unused_must_use)]
+
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+
+use egui::Widget;
+
+use crate::ErrorField;
+
+type Parse<'a>  = Box<dyn Fn(&str) -> Option<f64> + 'a>;
+type Format<'a> = Box<dyn Fn(f64) -> String + 'a>;
+
+/// An [`ErrorField`] for a number that can also be adjusted without typing: by dragging
+/// the handle next to it, with its step buttons, or with the arrow keys while it has focus.
+/// Holding shift makes the steps ten times larger and ctrl ten times smaller.
+///
+/// The number stays text, read and written by the given closures, so it can keep its
+/// separators and units. Adjustments are clamped to the range and make the response
+/// `changed`, while typed text is left to the caller to commit when the field loses focus.
+pub struct NumberField<'a>
+{
+    text: &'a mut String,
+    parse: Parse<'a>,
+    format: Format<'a>,
+    range: RangeInclusive<f64>,
+    step: f64,
+    error: Option<String>,
+    hint: &'a str,
+    width: Option<f32>,
+    inline: bool,
+    label: &'a str,
+    controls: [&'a str; 3]
+}
+
+impl<'a> NumberField<'a>
+{
+    pub fn new (text: &'a mut String) -> Self {
+        Self {
+            text,
+            parse: Box::new(|text| text.trim().parse().ok()),
+            format: Box::new(|value| value.to_string()),
+            range: f64::NEG_INFINITY..=f64::INFINITY,
+            step: 1.0,
+            error: None,
+            hint: "",
+            width: None,
+            inline: false,
+            label: "",
+            controls: ["Drag to adjust", "Decrease", "Increase"]
+        }
+    }
+
+    /// How the text is read, as a plain number by default.
+    #[must_use]
+    pub fn parse (mut self, parse: impl Fn(&str) -> Option<f64> + 'a) -> Self {
+        self.parse = Box::new(parse);
+        self
+    }
+
+    /// How an adjusted number is written back.
+    #[must_use]
+    pub fn format (mut self, format: impl Fn(f64) -> String + 'a) -> Self {
+        self.format = Box::new(format);
+        self
+    }
+
+    /// The bounds of adjusted numbers. Typed ones are for the caller to check.
+    #[must_use]
+    pub const fn range (mut self, range: RangeInclusive<f64>) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// The change per key press or click, and per point dragged.
+    /// Adjusted numbers are rounded to it.
+    #[must_use]
+    pub const fn step (mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// See [`ErrorField::error`]. The first error is kept.
+    #[must_use]
+    pub fn error<E: Display> (mut self, error: Option<E>) -> Self {
+        if  self.error.is_none() {
+            self.error = error.map(|e| e.to_string());
+        }
+        self
+    }
+
+    #[must_use]
+    pub const fn hint (mut self, hint: &'a str) -> Self {
+        self.hint = hint;
+        self
+    }
+
+    #[must_use]
+    pub const fn width (mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// See [`ErrorField::inline`].
+    #[must_use]
+    pub const fn inline (mut self, inline: bool) -> Self {
+        self.inline = inline;
+        self
+    }
+
+    /// See [`ErrorField::label`].
+    #[must_use]
+    pub const fn label (mut self, label: &'a str) -> Self {
+        self.label = label;
+        self
+    }
+
+    /// The names screen readers give the drag handle and the buttons stepping down and up.
+    #[must_use]
+    pub const fn controls (mut self, names: [&'a str; 3]) -> Self {
+        self.controls = names;
+        self
+    }
+}
+
+impl Widget for NumberField<'_>
+{
+    fn ui (self, ui: &mut egui::Ui) -> egui::Response {
+        let Self { text, parse, format, range, step, error, hint, width, inline, label, controls } = self;
+        let (low, high) = (*range.start(), *range.end());
+        let adjust = |text: &mut String, value: f64, step: f64| {
+            let value = if step > 0.0 { (value / step).round() * step } else { value };
+            *text = format(value.clamp(low, high));
+        };
+        ui.horizontal(|ui| {
+            ui.spacing_mut().item_spacing.x = 2.0;
+            let id = ui.next_auto_id();
+            let current = parse(text).unwrap_or(0.0).clamp(low, high);
+            let keyed = if ui.memory(|m| m.has_focus(id)) { arrows(ui.ctx()) } else { 0.0 };
+            if  keyed != 0.0 {
+                adjust(text, current + keyed * step, step * keyed.abs());
+            }
+            let field = ErrorField::new(text).error(error).hint(hint).inline(inline).numeric().label(label).id(id);
+            let field = ui.add(match width { Some(width) => field.width(width), None => field });
+            let handle = ui.add(egui::Label::new("↔").selectable(false).sense(egui::Sense::drag())).on_hover_cursor(egui::CursorIcon::ResizeHorizontal);
+            let down = ui.add(egui::Button::new("−").small());
+            let up = ui.add(egui::Button::new("+").small());
+            for (control, name) in [&handle, &down, &up].into_iter().zip(controls) {
+                ui.ctx().accesskit_node_builder(control.id, |node| node.set_label(name));
+            }
+            let scale = ui.input(|i| scale(i.modifiers));
+            let mut adjusted = keyed != 0.0;
+            if  down.clicked() || up.clicked() {
+                let sign = if up.clicked() { 1.0 } else { -1.0 };
+                adjust(text, parse(text).unwrap_or(current) + sign * scale * step, scale * step);
+                adjusted = true;
+            }
+            // The exact value is kept while dragging, as the text may be rounded to fewer decimals than a step.
+            if  handle.drag_started() {
+                ui.data_mut(|d| d.insert_temp(handle.id, current));
+            }
+            let moved = handle.drag_delta();
+            let moved = f64::from(moved.x - moved.y);
+            if  handle.dragged() && moved != 0.0 {
+                let value = ui.data(|d| d.get_temp(handle.id)).unwrap_or(current) + moved * scale * step;
+                let value = value.clamp(low, high);
+                ui.data_mut(|d| d.insert_temp(handle.id, value));
+                adjust(text, value, scale * step);
+                adjusted = true;
+            }
+            // The text edit is changed by every key typed, so only the adjustments are reported,
+            // while focus is still that of the text edit.
+            let mut response = handle.union(down).union(up);
+            response.id = field.id;
+            response.rect = response.rect.union(field.rect);
+            if  adjusted {
+                response.mark_changed();
+            }
+            response
+        }).inner
+    }
+}
+
+fn scale (modifiers: egui::Modifiers) -> f64 {
+    if  modifiers.command {
+        0.1
+    } else if modifiers.shift {
+        10.0
+    } else {
+        1.0
+    }
+}
+
+// Up or down in steps, scaled by the modifiers held.
+fn arrows (context: &egui::Context) -> f64 {
+    context.input_mut(|i| {
+        let scale = scale(i.modifiers);
+        let modifiers = if i.modifiers.command { egui::Modifiers::COMMAND } else { egui::Modifiers::NONE }; // Shift is ignored when matching keys.
+        if  i.consume_key(modifiers, egui::Key::ArrowUp) {
+            scale
+        } else if i.consume_key(modifiers, egui::Key::ArrowDown) {
+            -scale
+        } else {
+            0.0
+        }
+    })
+}
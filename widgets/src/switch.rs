@@ -0,0 +1,147 @@
+#![deny(clippy::pedantic)]
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+#![deny(clippy::panic)]
+#![deny(unused_must_use)]
+
+use egui::Widget;
+
+enum State<'a>
+{
+    Bound(&'a mut bool),
+    Mixed(&'a mut Option<bool>) // None puts the knob in the middle.
+}
+
+/// An on/off switch with an animated knob. Disable it with `ui.add_enabled`.
+pub struct Switch<'a>
+{
+    state: State<'a>,
+    label: &'a str,
+    on_text: &'a str,
+    off_text: &'a str,
+    height: Option<f32>,
+    track: Option<egui::Color32>,
+    knob: Option<egui::Color32>
+}
+
+impl<'a> Switch<'a>
+{
+    /// Flips `state` itself when clicked.
+    pub const fn bind (state: &'a mut bool) -> Self {
+        Self::with(State::Bound(state))
+    }
+
+    /// A switch that can also be neither on nor off. Clicking turns it on, then flips it.
+    pub const fn tri (state: &'a mut Option<bool>) -> Self {
+        Self::with(State::Mixed(state))
+    }
+
+    const fn with (state: State<'a>) -> Self {
+        Self {
+            state,
+            label: "",
+            on_text: "",
+            off_text: "",
+            height: None,
+            track: None,
+            knob: None
+        }
+    }
+
+    /// Names the switch for screen readers.
+    #[must_use]
+    pub const fn label (mut self, label: &'a str) -> Self {
+        self.label = label;
+        self
+    }
+
+    /// Short texts shown in the track, beside the knob.
+    #[must_use]
+    pub const fn texts (mut self, on: &'a str, off: &'a str) -> Self {
+        self.on_text = on;
+        self.off_text = off;
+        self
+    }
+
+    /// Height of the track, the width follows. Defaults to the height of a button.
+    #[must_use]
+    pub const fn size (mut self, height: f32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Colors of the track when on and of the knob, instead of those of the style.
+    #[must_use]
+    pub const fn colors (mut self, track: egui::Color32, knob: egui::Color32) -> Self {
+        self.track = Some(track);
+        self.knob = Some(knob);
+        self
+    }
+
+    fn get (&self) -> Option<bool> {
+        match &self.state {
+            State::Bound(on) => Some(**on),
+            State::Mixed(on) => **on
+        }
+    }
+
+    fn flip (&mut self) {
+        match &mut self.state {
+            State::Bound(on) => **on = !**on,
+            State::Mixed(on) => **on = Some(!on.unwrap_or(false))
+        }
+    }
+}
+
+impl Widget for Switch<'_>
+{
+    fn ui (mut self, ui: &mut egui::Ui) -> egui::Response {
+        let height = self.height.unwrap_or(ui.spacing().interact_size.y);
+        let font = egui::FontId::proportional(0.6 * height);
+        let text = [self.on_text, self.off_text].map(|t| ui.painter().layout_no_wrap(t.to_string(), font.clone(), egui::Color32::PLACEHOLDER).size().x);
+        let size = egui::vec2((2.5 * height).max(text[0].max(text[1]) + 1.5 * height), height);
+        let (area, mut response) = ui.allocate_exact_size(size, egui::Sense::click());
+        if response.clicked() {
+            self.flip();
+            response.mark_changed();
+        }
+        let state = self.get();
+        let on = state == Some(true);
+        response.widget_info(|| {
+            let mut info = egui::WidgetInfo::selected(egui::WidgetType::Checkbox, ui.is_enabled(), on, self.label);
+            info.selected = state; // Mixed has no selection.
+            info
+        });
+        if ui.is_rect_visible(area) {
+            let easing  = match state {
+                Some(on) => ui.ctx().animate_bool_responsive(response.id, on),
+                None     => ui.ctx().animate_value_with_time(response.id, 0.5, ui.style().animation_time)
+            };
+            let visuals = ui.style().interact_selectable(&response, on);
+            let area    = area.expand(visuals.expansion);
+            let radius  = 0.5 * area.height();
+            let track   = self.track.filter(|_| on && ui.is_enabled()).unwrap_or(visuals.bg_fill);
+            ui.painter().rect(area, radius, track, visuals.bg_stroke); // Paint "slider" beneath.
+            let circle  = egui::lerp((area.left() + radius - 2.0)..=(area.right() - radius), easing);
+            let center  = egui::pos2(circle, area.center().y);
+            if  state.is_some() {
+                let (edge, align, label) = if on {
+                    (area.left() + 0.5 * radius, egui::Align2::LEFT_CENTER, self.on_text)
+                } else {
+                    (area.right() - 0.5 * radius, egui::Align2::RIGHT_CENTER, self.off_text)
+                };
+                ui.painter().text(egui::pos2(edge, area.center().y), align, label, font, visuals.text_color());
+            }
+            let knob    = self.knob.filter(|_| ui.is_enabled()).unwrap_or(visuals.weak_bg_fill);
+            ui.painter().circle(center, 0.8 * radius, knob, visuals.fg_stroke); // Paint "knob" above.
+            if response.has_focus() {
+                ui.painter().rect_stroke(area.expand(2.0), radius + 2.0, ui.visuals().selection.stroke); // Paint focus ring around.
+            }
+        }
+        response
+    }
+}
+// Alternative way without struct, lifetimes and what not.
+// pub fn toggle (state: &mut bool) -> impl egui::Widget + '_ {
+//     move |ui: &mut egui::Ui| switch(ui, state)
+// }
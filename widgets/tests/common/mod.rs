@@ -0,0 +1,37 @@
+// Runs widgets in a headless egui context, feeding it pointer and keyboard events.
+// Each test file uses its own part of this.
+#![allow(dead_code)]
+
+use egui::{
+    Event,
+    Modifiers,
+    PointerButton,
+    Pos2
+};
+
+pub fn frame (ctx: &egui::Context, events: Vec<Event>, mut add_contents: impl FnMut(&mut egui::Ui)) -> egui::FullOutput {
+    let input = egui::RawInput {
+        screen_rect: Some(egui::Rect::from_min_size(Pos2::ZERO, egui::vec2(400.0, 300.0))),
+        events,
+        ..Default::default()
+    };
+    ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| add_contents(ui));
+    })
+}
+
+pub fn press (pos: Pos2) -> Vec<Event> {
+    vec![Event::PointerMoved(pos), button(pos, true)]
+}
+
+pub fn release (pos: Pos2) -> Vec<Event> {
+    vec![button(pos, false)]
+}
+
+fn button (pos: Pos2, pressed: bool) -> Event {
+    Event::PointerButton { pos, button: PointerButton::Primary, pressed, modifiers: Modifiers::NONE }
+}
+
+pub fn key (key: egui::Key) -> Vec<Event> {
+    vec![Event::Key { key, physical_key: None, pressed: true, repeat: false, modifiers: Modifiers::NONE }]
+}
@@ -0,0 +1,123 @@
+#![cfg(feature = "errorfield")]
+
+mod common;
+
+use compounder_widgets::ErrorField;
+
+const MASK: &str = "YYYY-MM-DD";
+
+fn type_into (frames: Vec<Vec<egui::Event>>) -> String {
+    type_into_mask(MASK, frames)
+}
+
+// Focuses an empty masked field, then sends it one frame of events after the other.
+fn type_into_mask (mask: &str, frames: Vec<Vec<egui::Event>>) -> String {
+    let ctx = egui::Context::default();
+    let mut value = String::new();
+    common::frame(&ctx, vec![], |ui| ui.add(ErrorField::new(&mut value).mask(mask)).request_focus());
+    common::frame(&ctx, vec![], |ui| { ui.add(ErrorField::new(&mut value).mask(mask)); });
+    for events in frames {
+        common::frame(&ctx, events, |ui| { ui.add(ErrorField::new(&mut value).mask(mask)); });
+    }
+    value
+}
+
+fn text (text: &str) -> Vec<egui::Event> {
+    vec![egui::Event::Text(text.to_string())]
+}
+
+#[test]
+fn mask_inserts_separators () {
+    assert_eq!(type_into(vec![text("20231231")]), "2023-12-31");
+    assert_eq!(type_into(vec![text("2023"), text("12")]), "2023-12");
+}
+
+#[test]
+fn mask_drops_impossible_digits () {
+    assert_eq!(type_into(vec![text("2023-13-45")]), "2023-1");
+    assert_eq!(type_into(vec![text("2023/x1/0a4")]), "2023-10");
+    assert_eq!(type_into(vec![text("2023/x1/0a2")]), "2023-10-2");
+}
+
+#[test]
+fn arrows_step_the_part_under_the_cursor () {
+    // Typing leaves the cursor at the end, in the day.
+    assert_eq!(type_into(vec![text("20231231"), common::key(egui::Key::ArrowUp)]), "2023-12-01");
+    assert_eq!(type_into(vec![text("20230101"), common::key(egui::Key::ArrowDown)]), "2023-01-31");
+    assert_eq!(type_into(vec![text("20230101"), common::key(egui::Key::ArrowLeft), common::key(egui::Key::ArrowLeft), common::key(egui::Key::ArrowLeft), common::key(egui::Key::ArrowDown)]), "2023-12-01");
+}
+
+#[test]
+fn days_fit_the_month () {
+    assert_eq!(type_into(vec![text("20230231")]), "2023-02-1");
+    assert_eq!(type_into(vec![text("20230229")]), "2023-02-2");
+    assert_eq!(type_into(vec![text("20240229")]), "2024-02-29");
+    assert_eq!(type_into(vec![text("20230431")]), "2023-04-3");
+    assert_eq!(type_into_mask("DD·MM·YYYY", vec![text("3102")]), "31·0");
+    assert_eq!(type_into_mask("DD·MM·YYYY", vec![text("29022023")]), "29·02·202");
+}
+
+#[test]
+fn arrows_keep_days_within_the_month () {
+    assert_eq!(type_into(vec![text("20230228"), common::key(egui::Key::ArrowUp)]), "2023-02-01");
+    assert_eq!(type_into(vec![text("20240228"), common::key(egui::Key::ArrowUp)]), "2024-02-29");
+    assert_eq!(type_into(vec![text("20230131"), common::key(egui::Key::ArrowLeft), common::key(egui::Key::ArrowLeft), common::key(egui::Key::ArrowLeft), common::key(egui::Key::ArrowUp)]), "2023-02-28");
+}
+
+#[test]
+fn mask_with_non_ascii_separators () {
+    assert_eq!(type_into_mask("DD·MM·YYYY", vec![text("31122023")]), "31·12·2023");
+    assert_eq!(type_into_mask("DD·MM·YYYY", vec![text("3113")]), "31·1");
+    assert_eq!(type_into_mask("YYYY年MM月DD", vec![text("20231231"), common::key(egui::Key::ArrowUp)]), "2023年12月01");
+    assert_eq!(type_into_mask("DD·MM·YYYY", vec![text("31122023"), common::key(egui::Key::ArrowDown)]), "31·12·2022");
+}
+
+#[test]
+fn validator_error_marks_field_invalid () {
+    let ctx = egui::Context::default();
+    ctx.enable_accesskit();
+    let mut value = String::from("abc");
+    let output = common::frame(&ctx, vec![], |ui| {
+        ui.add(ErrorField::new(&mut value).hint("a number").validate(|text| text.parse::<f64>().map_err(|_| "not a number")));
+    });
+    let update = output.platform_output.accesskit_update.unwrap_or_else(|| unreachable!("accesskit is enabled"));
+    let invalid: Vec<_> = update.nodes.iter().filter(|(_, node)| node.invalid().is_some()).collect();
+    assert_eq!(invalid.len(), 1);
+    assert_eq!(invalid[0].1.description(), Some("not a number"));
+}
+
+#[test]
+fn valid_text_has_no_error () {
+    let ctx = egui::Context::default();
+    ctx.enable_accesskit();
+    let mut value = String::from("12.5");
+    let output = common::frame(&ctx, vec![], |ui| {
+        ui.add(ErrorField::new(&mut value).inline(true).numeric().validate(|text| text.parse::<f64>()).error(None::<&str>));
+    });
+    let update = output.platform_output.accesskit_update.unwrap_or_else(|| unreachable!("accesskit is enabled"));
+    assert!(update.nodes.iter().all(|(_, node)| node.invalid().is_none()));
+}
+
+#[test]
+fn first_error_is_kept () {
+    let ctx = egui::Context::default();
+    ctx.enable_accesskit();
+    let mut value = String::new();
+    let output = common::frame(&ctx, vec![], |ui| {
+        ui.add(ErrorField::new(&mut value).error(Some("first")).validate(|_| Err::<(), _>("second")));
+    });
+    let update = output.platform_output.accesskit_update.unwrap_or_else(|| unreachable!("accesskit is enabled"));
+    assert!(update.nodes.iter().any(|(_, node)| node.description() == Some("first")));
+}
+
+#[test]
+fn label_names_the_field () {
+    let ctx = egui::Context::default();
+    ctx.enable_accesskit();
+    let mut value = String::new();
+    let output = common::frame(&ctx, vec![], |ui| {
+        ui.add(ErrorField::new(&mut value).hint("2025-12-31").label("Start date"));
+    });
+    let update = output.platform_output.accesskit_update.unwrap_or_else(|| unreachable!("accesskit is enabled"));
+    assert!(update.nodes.iter().any(|(_, node)| node.role() == egui::accesskit::Role::TextInput && node.label() == Some("Start date")));
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "gradient")]
+
+use compounder_widgets::gradient::{
+    Gradient,
+    Interpolation,
+    Shape,
+    TextureManager
+};
+use egui::Color32;
+
+fn two (interpolation: Interpolation, shape: Shape) -> Gradient {
+    Gradient::new(vec![(0.0, Color32::BLACK), (1.0, Color32::WHITE)], interpolation, shape)
+}
+
+#[test]
+fn samples_are_clamped_to_the_end_stops () {
+    let g = two(Interpolation::Gamma, Shape::Linear(90.0));
+    assert_eq!(g.sample(-1.0), Color32::BLACK);
+    assert_eq!(g.sample(0.0), Color32::BLACK);
+    assert_eq!(g.sample(1.0), Color32::WHITE);
+    assert_eq!(g.sample(2.0), Color32::WHITE);
+}
+
+#[test]
+fn stops_are_sorted_and_hit_exactly () {
+    let g = Gradient::new(vec![(1.0, Color32::BLUE), (0.0, Color32::RED), (0.5, Color32::GREEN)], Interpolation::Linear, Shape::Radial);
+    assert_eq!(g.sample(0.0), Color32::RED);
+    assert_eq!(g.sample(0.5), Color32::GREEN);
+    assert_eq!(g.sample(1.0), Color32::BLUE);
+}
+
+#[test]
+fn interpolations_differ_in_the_middle () {
+    let gamma  = two(Interpolation::Gamma,  Shape::Radial).sample(0.5);
+    let linear = two(Interpolation::Linear, Shape::Radial).sample(0.5);
+    let oklab  = two(Interpolation::Oklab,  Shape::Radial).sample(0.5);
+    assert_eq!(gamma.r(), 128);
+    assert!(linear.r() > gamma.r(), "half the light is brighter than half the sRGB value");
+    assert!(oklab.r() > 90 && oklab.r() < 110, "Oklab middle gray was {}", oklab.r());
+    assert_eq!(oklab.r(), oklab.b());
+}
+
+#[test]
+fn linear_gradients_are_rows_and_radial_ones_squares () {
+    let row = two(Interpolation::Gamma, Shape::Linear(45.0)).image();
+    assert_eq!(row.size[1], 1);
+    assert_eq!(row.pixels.first(), Some(&Color32::BLACK));
+    assert_eq!(row.pixels.last(), Some(&Color32::WHITE));
+    let disc = two(Interpolation::Gamma, Shape::Radial).image();
+    assert_eq!(disc.size[0], disc.size[1]);
+    assert_eq!(disc.pixels.len(), disc.size[0] * disc.size[1]);
+    assert_eq!(disc.pixels[0], Color32::WHITE, "corners are past the last stop");
+}
+
+#[test]
+fn cache_hits_ignore_the_angle () {
+    let ctx = egui::Context::default();
+    let mut textures = TextureManager::default();
+    let first = textures.get(&ctx, &two(Interpolation::Gamma, Shape::Linear(0.0))).id();
+    let again = textures.get(&ctx, &two(Interpolation::Gamma, Shape::Linear(90.0))).id();
+    assert_eq!(first, again);
+    textures.get(&ctx, &two(Interpolation::Oklab, Shape::Linear(0.0)));
+    let stats = textures.stats();
+    assert_eq!((stats.hits, stats.misses, stats.textures), (1, 2, 2));
+}
+
+#[test]
+fn cache_evicts_the_least_recently_used () {
+    let ctx = egui::Context::default();
+    let mut textures = TextureManager::default();
+    let gray = |i: u8| Gradient::new(vec![(0.0, Color32::from_gray(i)), (1.0, Color32::WHITE)], Interpolation::Gamma, Shape::Linear(0.0));
+    for i in 0..100 {
+        textures.get(&ctx, &gray(i));
+        textures.get(&ctx, &gray(0)); // Kept in use, so never evicted.
+    }
+    let stats = textures.stats();
+    assert!(stats.textures < 100);
+    assert!(stats.evictions > 0);
+    let misses = stats.misses;
+    textures.get(&ctx, &gray(0));
+    assert_eq!(textures.stats().misses, misses);
+    textures.get(&ctx, &gray(1));
+    assert_eq!(textures.stats().misses, misses + 1);
+}
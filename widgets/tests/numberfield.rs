@@ -0,0 +1,67 @@
+#![cfg(feature = "numberfield")]
+
+mod common;
+
+use compounder_widgets::NumberField;
+
+fn field (text: &mut String) -> NumberField<'_> {
+    NumberField::new(text).range(0.0..=1.0).step(0.25)
+}
+
+// Focuses the field, then sends it one frame of events after the other,
+// returning whether the last frame reported a change.
+fn send (text: &mut String, frames: Vec<Vec<egui::Event>>) -> bool {
+    let ctx = egui::Context::default();
+    common::frame(&ctx, vec![], |ui| ui.add(field(text)).request_focus());
+    let mut changed = false;
+    for events in frames {
+        common::frame(&ctx, events, |ui| changed = ui.add(field(text)).changed());
+    }
+    changed
+}
+
+#[test]
+fn arrows_step_within_range () {
+    let mut text = String::from("0.5");
+    assert!(send(&mut text, vec![common::key(egui::Key::ArrowUp)]));
+    assert_eq!(text, "0.75");
+    send(&mut text, vec![common::key(egui::Key::ArrowUp), common::key(egui::Key::ArrowUp)]);
+    assert_eq!(text, "1");
+    send(&mut text, vec![common::key(egui::Key::ArrowDown)]);
+    assert_eq!(text, "0.75");
+}
+
+#[test]
+fn typing_is_not_reported_as_change () {
+    let mut text = String::from("0.5");
+    assert!(!send(&mut text, vec![vec![egui::Event::Text(String::from("9"))]]));
+    assert_eq!(text, "0.59");
+}
+
+#[test]
+fn plus_button_steps_and_rounds () {
+    let ctx = egui::Context::default();
+    let mut text = String::from("0.3");
+    let mut rect = egui::Rect::NOTHING;
+    common::frame(&ctx, vec![], |ui| rect = ui.add(field(&mut text)).rect);
+    let plus = rect.right_center() - egui::vec2(4.0, 0.0); // The last thing laid out.
+    common::frame(&ctx, common::press(plus), |ui| { ui.add(field(&mut text)); });
+    let mut changed = false;
+    common::frame(&ctx, common::release(plus), |ui| changed = ui.add(field(&mut text)).changed());
+    assert!(changed);
+    assert_eq!(text, "0.5");
+}
+
+#[test]
+fn controls_have_names () {
+    let ctx = egui::Context::default();
+    ctx.enable_accesskit();
+    let mut text = String::from("0.5");
+    let output = common::frame(&ctx, vec![], |ui| {
+        ui.add(field(&mut text).label("Rate").controls(["Drag", "Down", "Up"]));
+    });
+    let update = output.platform_output.accesskit_update.unwrap_or_else(|| unreachable!("accesskit is enabled"));
+    for name in ["Rate", "Drag", "Down", "Up"] {
+        assert!(update.nodes.iter().any(|(_, node)| node.label() == Some(name)), "no control named {name}");
+    }
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "switch")]
+
+mod common;
+
+use compounder_widgets::Switch;
+
+// Clicks the center of whatever `add_contents` lays out, returning whether it reported a change.
+fn click (mut add_contents: impl FnMut(&mut egui::Ui) -> egui::Response) -> bool {
+    let ctx = egui::Context::default();
+    let mut rect = egui::Rect::NOTHING;
+    common::frame(&ctx, vec![], |ui| rect = add_contents(ui).rect);
+    common::frame(&ctx, common::press(rect.center()), |ui| { add_contents(ui); });
+    let mut changed = false;
+    common::frame(&ctx, common::release(rect.center()), |ui| changed = add_contents(ui).changed());
+    changed
+}
+
+#[test]
+fn bound_switch_flips_its_state () {
+    let mut on = false;
+    assert!(click(|ui| ui.add(Switch::bind(&mut on))));
+    assert!(on);
+    assert!(click(|ui| ui.add(Switch::bind(&mut on).texts("on", "off").size(12.0))));
+    assert!(!on);
+}
+
+#[test]
+fn tri_state_switch_turns_on_then_flips () {
+    let mut state = None;
+    assert!(click(|ui| ui.add(Switch::tri(&mut state))));
+    assert_eq!(state, Some(true));
+    click(|ui| ui.add(Switch::tri(&mut state)));
+    assert_eq!(state, Some(false));
+}
+
+#[test]
+fn disabled_switch_keeps_its_state () {
+    let mut on = false;
+    assert!(!click(|ui| ui.add_enabled(false, Switch::bind(&mut on))));
+    assert!(!on);
+}
+
+#[test]
+fn unclicked_switch_is_unchanged () {
+    let ctx = egui::Context::default();
+    let mut on = true;
+    let mut changed = true;
+    common::frame(&ctx, vec![], |ui| changed = ui.add(Switch::bind(&mut on).colors(egui::Color32::RED, egui::Color32::WHITE)).changed());
+    assert!(!changed);
+    assert!(on);
+}